@@ -0,0 +1,13 @@
+//! This module holds all wrappers around inputs that extend the matching functionality.
+
+mod fragment;
+pub use fragment::*;
+
+mod located;
+pub use located::*;
+
+mod partial;
+pub use partial::*;
+
+mod trivia;
+pub use trivia::*;
@@ -0,0 +1,114 @@
+use core::convert::TryFrom;
+
+use crate::{input::Spacing, result::MatchFailed};
+
+/// Provides the measuring and splitting of inputs, shared by the input wrappers so they can wrap each other.
+/// Bounds like the one of `AlternativesMatch::longest` rely on it to measure the "rest" part.
+pub trait Fragment: Copy {
+    /// The type of the elements (`char` in case of `&str`).
+    type Element;
+
+    /// Returns the length in elements (bytes in case of `&str`).
+    fn length(self) -> usize;
+
+    /// Returns the amount of repetitions, i.e. elements (characters, or bytes if requested, in case of `&str`).
+    fn count(self, bytes: bool) -> usize;
+
+    /// Splits the input at the passed length, unless it's out of bounds or not at a character boundary.
+    fn split(self, at: usize) -> Option<(Self, Self)>;
+
+    /// Returns the character at the passed index together with its length, or `None` if there is none.
+    fn character(self, index: usize) -> Option<(char, usize)>
+    where
+        Self::Element: Copy + Into<char>;
+
+    /// Returns the length of the whitespace character at the passed index, or zero if there is none.
+    fn whitespace(self, index: usize, spacing: Spacing) -> usize
+    where
+        Self::Element: Copy + Into<char>;
+
+    /// Marks the failure as incomplete, if it was caused by reaching the end of input after which more may follow.
+    fn incomplete(self, error: MatchFailed) -> MatchFailed;
+}
+
+impl<E> Fragment for &[E] {
+    type Element = E;
+
+    fn length(self) -> usize {
+        self.len()
+    }
+
+    fn count(self, _: bool) -> usize {
+        self.len()
+    }
+
+    fn split(self, at: usize) -> Option<(Self, Self)> {
+        if at <= self.len() {
+            Some(self.split_at(at))
+        } else {
+            None
+        }
+    }
+
+    fn character(self, index: usize) -> Option<(char, usize)>
+    where
+        E: Copy + Into<char>,
+    {
+        self.get(index).map(|&element| (element.into(), 1))
+    }
+
+    fn whitespace(self, index: usize, spacing: Spacing) -> usize
+    where
+        E: Copy + Into<char>,
+    {
+        self.get(index)
+            .and_then(|&element| u8::try_from(element.into()).ok())
+            .map_or(0, |byte| spacing.byte(byte))
+    }
+
+    fn incomplete(self, error: MatchFailed) -> MatchFailed {
+        error
+    }
+}
+
+impl Fragment for &str {
+    type Element = char;
+
+    fn length(self) -> usize {
+        self.len()
+    }
+
+    fn count(self, bytes: bool) -> usize {
+        if bytes {
+            self.len()
+        } else {
+            self.chars().count()
+        }
+    }
+
+    fn split(self, at: usize) -> Option<(Self, Self)> {
+        if self.is_char_boundary(at) {
+            Some(self.split_at(at))
+        } else {
+            None
+        }
+    }
+
+    fn character(self, index: usize) -> Option<(char, usize)> {
+        self.get(index..)
+            .and_then(|rest| rest.chars().next())
+            .map(|c| (c, c.len_utf8()))
+    }
+
+    fn whitespace(self, index: usize, spacing: Spacing) -> usize {
+        match (spacing, self.character(index)) {
+            (Spacing::Unicode, Some((c, length))) if c.is_whitespace() => length,
+            (_, Some((c, _))) => u8::try_from(c).map_or(0, |byte| spacing.byte(byte)),
+            (_, None) => 0,
+        }
+    }
+
+    fn incomplete(self, error: MatchFailed) -> MatchFailed {
+        error
+    }
+}
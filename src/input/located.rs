@@ -0,0 +1,350 @@
+#[cfg(feature = "std")]
+use crate::traits::MatchKeywords;
+use crate::{
+    input::{Fragment, Spacing},
+    result::{MappedMatch, Match, MatchFailed, TransformMatch},
+    traits::{
+        MatchBinary, MatchFail, MatchNumber, MatchStatic, MatchStaticIgnoreCase, MatchString,
+        MatchUntil, MatchWith, MatchWithInRange, Recognize, Repetition, Sequence,
+    },
+};
+
+/// Represents a position within the original input.
+/// ## Notes
+/// Lines and columns start from one, while the offset starts from zero.
+/// Columns count characters in case of `&str` and bytes in case of `&[u8]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Location {
+    /// Constructs a new instance.
+    #[must_use]
+    pub const fn new(offset: usize, line: usize, column: usize) -> Self {
+        Self {
+            offset,
+            line,
+            column,
+        }
+    }
+
+    /// Returns the offset (in elements, bytes in case of `&str`) from the start of the original input.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the line number.
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column number.
+    #[must_use]
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the location right after the passed fragment, which starts at this location.
+    fn advance<I>(self, fragment: I) -> Self
+    where
+        I: Fragment,
+        I::Element: Copy + Into<char>,
+    {
+        let mut location: Self = self;
+        let mut index: usize = 0;
+
+        while let Some((c, length)) = fragment.character(index) {
+            location = if c == '\n' {
+                Self::new(location.offset + length, location.line + 1, 1)
+            } else {
+                Self::new(location.offset + length, location.line, location.column + 1)
+            };
+
+            index += length;
+        }
+
+        location
+    }
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Self::new(0, 1, 1)
+    }
+}
+
+/// Wraps an input (`&str` or `&[u8]`), keeping track of where it is located within the original input.
+/// ## Notes
+/// Both "matched" and "rest" parts produced by matching a located input are located too,
+/// which makes the "matched" parts usable as spans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Located<I> {
+    fragment: I,
+    location: Location,
+}
+
+impl<I> Located<I> {
+    /// Constructs a new instance, located at the start of the original input.
+    pub fn new(fragment: I) -> Self {
+        Self::with_location(fragment, Location::default())
+    }
+
+    /// Constructs a new instance, located at the passed location.
+    pub const fn with_location(fragment: I, location: Location) -> Self {
+        Self { fragment, location }
+    }
+
+    /// Returns a reference to the wrapped input.
+    pub const fn fragment(&self) -> &I {
+        &self.fragment
+    }
+
+    /// Returns the wrapped input, consuming the object.
+    pub fn into_fragment(self) -> I {
+        self.fragment
+    }
+
+    /// Returns the location of the start of the wrapped input.
+    pub const fn location(&self) -> Location {
+        self.location
+    }
+}
+
+impl<I> Located<I>
+where
+    I: Fragment,
+    I::Element: Copy + Into<char>,
+{
+    /// Returns the location right after the end of the wrapped input.
+    pub fn end(&self) -> Location {
+        self.location.advance(self.fragment)
+    }
+
+    /// Returns the location at which the passed failure has occurred.
+    /// Returns `None` when the failure's position is unknown or doesn't belong to this input.
+    pub fn locate(&self, error: &MatchFailed) -> Option<Location> {
+        let (before, _) = self
+            .fragment
+            .split(error.position(self.fragment.length())?)?;
+
+        Some(self.location.advance(before))
+    }
+
+    fn relocate(self, result: Match<I, I>) -> Match<Self, Self> {
+        let location: Location = self.location;
+        let length: usize = self.fragment.length();
+
+        result.transform(|matched, rest| {
+            let consumed: Option<(I, I)> = length
+                .checked_sub(rest.length())
+                .and_then(|consumed| self.fragment.split(consumed));
+
+            if let Some((consumed, _)) = consumed {
+                let rest: Self = Self::with_location(rest, location.advance(consumed));
+
+                match matched {
+                    Some(matched) => {
+                        TransformMatch::Full(Self::with_location(matched, location), rest)
+                    }
+                    None => TransformMatch::OnlyRest(rest),
+                }
+            } else {
+                TransformMatch::Failed
+            }
+        })
+    }
+
+    /// Same as `relocate`, but for mapped results.
+    fn relocate_mapped<V>(self, result: MappedMatch<I, I, V>) -> MappedMatch<Self, Self, V> {
+        match result.take() {
+            Ok((matched, rest)) => {
+                let consumed: Option<(Self, Self)> = self
+                    .fragment
+                    .length()
+                    .checked_sub(rest.length())
+                    .and_then(|consumed| self.split(consumed));
+
+                if let Some((_, after)) = consumed {
+                    MappedMatch::new(
+                        matched.map(|(matched, value)| {
+                            (Self::with_location(matched, self.location), value)
+                        }),
+                        Self::with_location(rest, after.location),
+                    )
+                } else {
+                    MappedMatch::failed()
+                }
+            }
+            Err(error) => MappedMatch::failed_with(error),
+        }
+    }
+}
+
+impl<I> Fragment for Located<I>
+where
+    I: Fragment,
+    I::Element: Copy + Into<char>,
+{
+    type Element = I::Element;
+
+    fn length(self) -> usize {
+        self.fragment.length()
+    }
+
+    fn count(self, bytes: bool) -> usize {
+        self.fragment.count(bytes)
+    }
+
+    fn split(self, at: usize) -> Option<(Self, Self)> {
+        let (before, after): (I, I) = self.fragment.split(at)?;
+
+        Some((
+            Self::with_location(before, self.location),
+            Self::with_location(after, self.location.advance(before)),
+        ))
+    }
+
+    fn character(self, index: usize) -> Option<(char, usize)> {
+        self.fragment.character(index)
+    }
+
+    fn whitespace(self, index: usize, spacing: Spacing) -> usize {
+        self.fragment.whitespace(index, spacing)
+    }
+
+    fn incomplete(self, error: MatchFailed) -> MatchFailed {
+        self.fragment.incomplete(error)
+    }
+}
+
+impl<I> Sequence for Located<I> where I: Sequence {}
+
+impl<F, V, I> MatchBinary<F, MappedMatch<Self, Self, V>> for Located<I>
+where
+    I: Fragment + MatchBinary<F, MappedMatch<I, I, V>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_binary(self, format: F) -> MappedMatch<Self, Self, V> {
+        self.relocate_mapped(self.fragment.match_binary(format))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, I> MatchKeywords<K, MappedMatch<Self, Self, V>> for Located<I>
+where
+    I: Fragment + MatchKeywords<K, MappedMatch<I, I, V>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_keywords(self, keywords: K) -> MappedMatch<Self, Self, V> {
+        self.relocate_mapped(self.fragment.match_keywords(keywords))
+    }
+}
+
+impl<F, N, I> MatchNumber<F, MappedMatch<Self, Self, N>> for Located<I>
+where
+    I: Fragment + MatchNumber<F, MappedMatch<I, I, N>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_number(self, format: F) -> MappedMatch<Self, Self, N> {
+        self.relocate_mapped(self.fragment.match_number(format))
+    }
+}
+
+impl<E, T, I> MatchStatic<E, T, Match<Self, Self>> for Located<I>
+where
+    I: Fragment + MatchStatic<E, T, Match<I, I>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_static(self, pattern: T) -> Match<Self, Self> {
+        self.relocate(self.fragment.match_static(pattern))
+    }
+}
+
+impl<E, T, I> MatchStaticIgnoreCase<E, T, Match<Self, Self>> for Located<I>
+where
+    I: Fragment + MatchStaticIgnoreCase<E, T, Match<I, I>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_static_ignore_case(self, pattern: T) -> Match<Self, Self> {
+        self.relocate(self.fragment.match_static_ignore_case(pattern))
+    }
+}
+
+impl<F, V, I> MatchString<F, MappedMatch<Self, Self, V>> for Located<I>
+where
+    I: Fragment + MatchString<F, MappedMatch<I, I, V>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_string(self, format: F) -> MappedMatch<Self, Self, V> {
+        self.relocate_mapped(self.fragment.match_string(format))
+    }
+}
+
+impl<E, T, I> MatchUntil<E, T, Match<Self, Self>> for Located<I>
+where
+    I: Fragment + MatchUntil<E, T, Match<I, I>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_until(self, pattern: T) -> Match<Self, Self> {
+        self.relocate(self.fragment.match_until(pattern))
+    }
+
+    fn match_until_including(self, pattern: T) -> Match<Self, Self> {
+        self.relocate(self.fragment.match_until_including(pattern))
+    }
+
+    fn match_until_found(self, pattern: T) -> Match<Self, Self> {
+        self.relocate(self.fragment.match_until_found(pattern))
+    }
+}
+
+impl<F, H, I> MatchWith<F, Match<Self, Self>, H> for Located<I>
+where
+    I: Fragment + MatchWith<F, Match<I, I>, H>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_with(self, pattern: F) -> Match<Self, Self> {
+        self.relocate(self.fragment.match_with(pattern))
+    }
+}
+
+impl<F, H1, H2, I> MatchWithInRange<F, Match<Self, Self>, H1, H2> for Located<I>
+where
+    I: Fragment + MatchWithInRange<F, Match<I, I>, H1, H2>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_range_with<B>(self, range: B, pattern: F) -> Match<Self, Self>
+    where
+        B: Repetition,
+    {
+        self.relocate(self.fragment.match_range_with(range, pattern))
+    }
+}
+
+impl<F, R, T, I> Recognize<F, Match<Self, Self>, T> for Located<I>
+where
+    I: Fragment,
+    F: FnOnce(Self) -> R,
+    R: Into<Match<T, Self>>,
+{
+    fn recognize(self, f: F) -> Match<Self, Self> {
+        f(self).into().transform(|_, rest| {
+            let consumed: Option<(I, I)> = self
+                .fragment
+                .length()
+                .checked_sub(rest.fragment.length())
+                .and_then(|consumed| self.fragment.split(consumed));
+
+            if let Some((consumed, _)) = consumed {
+                TransformMatch::Full(Self::with_location(consumed, self.location), rest)
+            } else {
+                TransformMatch::Failed
+            }
+        })
+    }
+}
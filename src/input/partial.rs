@@ -0,0 +1,345 @@
+use core::ops;
+
+#[cfg(feature = "std")]
+use crate::traits::MatchKeywords;
+use crate::{
+    input::{Fragment, Spacing},
+    result::{Expected, Found, MappedMatch, Match, MatchFailed, Needed, TransformMatch},
+    traits::{
+        limits, Bytes, MatchBinary, MatchFail, MatchNumber, MatchStatic, MatchStaticIgnoreCase,
+        MatchString, MatchUntil, MatchWith, MatchWithInRange, Recognize, Repetition, Sequence,
+    },
+};
+
+/// Describes whether a successful match could have consumed more input.
+enum Bound {
+    /// The match can't consume more input.
+    Closed,
+    /// The match could consume more input.
+    Open,
+    /// The match could consume more input, unless it already consumed the given amount of elements.
+    UpTo(usize),
+}
+
+/// Wraps an input (`&str` or `&[E]`) that is only a part of the whole input, e.g. a chunk received over network.
+/// ## Notes
+/// When matching reaches the end of a partial input, the result is marked as incomplete instead of failed or
+/// successful, as more input could change the outcome. See [`MatchFailed::needed`].
+/// Once the last part of the input is available, it should be wrapped using the [`last`] function, so reaching
+/// its end is treated as with ordinary inputs.
+///
+/// [`MatchFailed::needed`]: ../result/struct.MatchFailed.html#method.needed
+/// [`last`]: #method.last
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Partial<I> {
+    fragment: I,
+    last: bool,
+}
+
+impl<I> Partial<I> {
+    /// Constructs a new instance after which more input may follow.
+    pub const fn new(fragment: I) -> Self {
+        Self {
+            fragment,
+            last: false,
+        }
+    }
+
+    /// Constructs a new instance after which no more input will follow.
+    pub const fn last(fragment: I) -> Self {
+        Self {
+            fragment,
+            last: true,
+        }
+    }
+
+    /// Returns a reference to the wrapped input.
+    pub const fn fragment(&self) -> &I {
+        &self.fragment
+    }
+
+    /// Returns the wrapped input, consuming the object.
+    pub fn into_fragment(self) -> I {
+        self.fragment
+    }
+
+    /// Returns boolean indicating whether no more input will follow.
+    pub const fn is_last(&self) -> bool {
+        self.last
+    }
+}
+
+impl<I> Partial<I>
+where
+    I: Fragment,
+{
+    fn needed(&self, expected: &Expected, bytes: bool) -> Needed {
+        match expected {
+            Expected::Elements(length) => {
+                Needed::new(length.saturating_sub(self.fragment.length()))
+            }
+            Expected::Text(text) if !text.is_truncated() => {
+                Needed::new(text.as_str().len().saturating_sub(self.fragment.length()))
+            }
+            Expected::AtLeast(minimum)
+            | Expected::Between(minimum, _)
+            | Expected::Exactly(minimum) => {
+                Needed::new(minimum.saturating_sub(self.fragment.count(bytes)))
+            }
+            _ => Needed::Unknown,
+        }
+    }
+
+    fn wrap(self, result: Match<I, I>, bound: &Bound) -> Match<Self, Self> {
+        self.wrap_counted(result, bound, false)
+    }
+
+    /// Same as `wrap`, but counts repetitions of `&str` in bytes instead of characters, if requested.
+    fn wrap_counted(self, result: Match<I, I>, bound: &Bound, bytes: bool) -> Match<Self, Self> {
+        let wrap = |fragment: I| Self {
+            fragment,
+            last: self.last,
+        };
+
+        match result.take() {
+            Ok((matched, rest)) => {
+                let open: bool = match (bound, &matched) {
+                    (Bound::Closed, _) => false,
+                    (Bound::UpTo(maximum), Some(matched)) => matched.count(bytes) < *maximum,
+                    (Bound::Open, _) | (Bound::UpTo(_), None) => true,
+                };
+
+                if open && !self.last && rest.length() == 0 {
+                    Match::failed_with(
+                        MatchFailed::new(0, Expected::Unknown, Found::EndOfInput)
+                            .incomplete(Needed::new(1)),
+                    )
+                } else {
+                    Match::new(matched.map(wrap), wrap(rest))
+                }
+            }
+            Err(error) => Match::failed_with(self.incomplete_counted(error, bytes)),
+        }
+    }
+
+    /// Same as `wrap`, but for mapped results.
+    fn wrap_mapped<V>(
+        self,
+        result: MappedMatch<I, I, V>,
+        bound: &Bound,
+    ) -> MappedMatch<Self, Self, V> {
+        let wrap = |fragment: I| Self {
+            fragment,
+            last: self.last,
+        };
+
+        match result.take() {
+            Ok((_, rest))
+                if !matches!(bound, Bound::Closed) && !self.last && rest.length() == 0 =>
+            {
+                MappedMatch::failed_with(
+                    MatchFailed::new(0, Expected::Unknown, Found::EndOfInput)
+                        .incomplete(Needed::new(1)),
+                )
+            }
+            Ok((matched, rest)) => MappedMatch::new(
+                matched.map(|(matched, value)| (wrap(matched), value)),
+                wrap(rest),
+            ),
+            Err(error) => MappedMatch::failed_with(self.incomplete_counted(error, false)),
+        }
+    }
+
+    /// Same as `incomplete`, but counts repetitions of `&str` in bytes instead of characters, if requested.
+    fn incomplete_counted(&self, error: MatchFailed, bytes: bool) -> MatchFailed {
+        if !self.last && error.found() == &Found::EndOfInput {
+            let needed: Needed = self.needed(error.expected(), bytes);
+
+            error.incomplete(needed)
+        } else {
+            error
+        }
+    }
+}
+
+impl<I> Fragment for Partial<I>
+where
+    I: Fragment,
+{
+    type Element = I::Element;
+
+    fn length(self) -> usize {
+        self.fragment.length()
+    }
+
+    fn count(self, bytes: bool) -> usize {
+        self.fragment.count(bytes)
+    }
+
+    fn split(self, at: usize) -> Option<(Self, Self)> {
+        let wrap = |fragment: I| Self {
+            fragment,
+            last: self.last,
+        };
+
+        self.fragment
+            .split(at)
+            .map(|(before, after)| (wrap(before), wrap(after)))
+    }
+
+    fn character(self, index: usize) -> Option<(char, usize)>
+    where
+        I::Element: Copy + Into<char>,
+    {
+        self.fragment.character(index)
+    }
+
+    fn whitespace(self, index: usize, spacing: Spacing) -> usize
+    where
+        I::Element: Copy + Into<char>,
+    {
+        self.fragment.whitespace(index, spacing)
+    }
+
+    fn incomplete(self, error: MatchFailed) -> MatchFailed {
+        self.incomplete_counted(self.fragment.incomplete(error), false)
+    }
+}
+
+impl<I> Sequence for Partial<I> where I: Sequence {}
+
+impl<F, V, I> MatchBinary<F, MappedMatch<Self, Self, V>> for Partial<I>
+where
+    I: Fragment + MatchBinary<F, MappedMatch<I, I, V>>,
+{
+    fn match_binary(self, format: F) -> MappedMatch<Self, Self, V> {
+        self.wrap_mapped(self.fragment.match_binary(format), &Bound::Closed)
+    }
+}
+
+impl<E, T, I> MatchStatic<E, T, Match<Self, Self>> for Partial<I>
+where
+    I: Fragment + MatchStatic<E, T, Match<I, I>>,
+{
+    fn match_static(self, pattern: T) -> Match<Self, Self> {
+        self.wrap(self.fragment.match_static(pattern), &Bound::Closed)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, I> MatchKeywords<K, MappedMatch<Self, Self, V>> for Partial<I>
+where
+    I: Fragment + MatchKeywords<K, MappedMatch<I, I, V>>,
+{
+    fn match_keywords(self, keywords: K) -> MappedMatch<Self, Self, V> {
+        // Reaching the end of the fragment leaves room for a longer keyword in more input.
+        self.wrap_mapped(self.fragment.match_keywords(keywords), &Bound::Open)
+    }
+}
+
+impl<F, N, I> MatchNumber<F, MappedMatch<Self, Self, N>> for Partial<I>
+where
+    I: Fragment + MatchNumber<F, MappedMatch<I, I, N>>,
+{
+    fn match_number(self, format: F) -> MappedMatch<Self, Self, N> {
+        // Reaching the end of the fragment leaves room for more digits in more input.
+        self.wrap_mapped(self.fragment.match_number(format), &Bound::Open)
+    }
+}
+
+impl<E, T, I> MatchStaticIgnoreCase<E, T, Match<Self, Self>> for Partial<I>
+where
+    I: Fragment + MatchStaticIgnoreCase<E, T, Match<I, I>>,
+{
+    fn match_static_ignore_case(self, pattern: T) -> Match<Self, Self> {
+        self.wrap(
+            self.fragment.match_static_ignore_case(pattern),
+            &Bound::Closed,
+        )
+    }
+}
+
+impl<F, V, I> MatchString<F, MappedMatch<Self, Self, V>> for Partial<I>
+where
+    I: Fragment + MatchString<F, MappedMatch<I, I, V>>,
+{
+    fn match_string(self, format: F) -> MappedMatch<Self, Self, V> {
+        self.wrap_mapped(self.fragment.match_string(format), &Bound::Closed)
+    }
+}
+
+impl<E, T, I> MatchUntil<E, T, Match<Self, Self>> for Partial<I>
+where
+    I: Fragment + MatchUntil<E, T, Match<I, I>>,
+{
+    fn match_until(self, pattern: T) -> Match<Self, Self> {
+        // Not finding the pattern consumes the whole fragment, while more input could contain it.
+        self.wrap(self.fragment.match_until(pattern), &Bound::Open)
+    }
+
+    fn match_until_including(self, pattern: T) -> Match<Self, Self> {
+        self.wrap(self.fragment.match_until_including(pattern), &Bound::Closed)
+    }
+
+    fn match_until_found(self, pattern: T) -> Match<Self, Self> {
+        self.wrap(self.fragment.match_until_found(pattern), &Bound::Closed)
+    }
+}
+
+impl<F, H, I> MatchWith<F, Match<Self, Self>, H> for Partial<I>
+where
+    I: Fragment + MatchWith<F, Match<I, I>, H>,
+{
+    fn match_with(self, pattern: F) -> Match<Self, Self> {
+        self.wrap(self.fragment.match_with(pattern), &Bound::Open)
+    }
+}
+
+impl<F, H1, H2, I> MatchWithInRange<F, Match<Self, Self>, H1, H2> for Partial<I>
+where
+    I: Fragment + MatchWithInRange<F, Match<I, I>, H1, H2>,
+{
+    fn match_range_with<B>(self, range: B, pattern: F) -> Match<Self, Self>
+    where
+        B: Repetition,
+    {
+        let bytes: bool = range.counts_bytes();
+
+        let bounds: (ops::Bound<usize>, ops::Bound<usize>) = range.bounds();
+
+        let bound: Bound = match limits(bounds) {
+            Some((_, Some(maximum))) => Bound::UpTo(maximum),
+            _ => Bound::Open,
+        };
+
+        let result: Match<I, I> = if bytes {
+            self.fragment.match_range_with(Bytes(bounds), pattern)
+        } else {
+            self.fragment.match_range_with(bounds, pattern)
+        };
+
+        self.wrap_counted(result, &bound, bytes)
+    }
+}
+
+impl<F, R, T, I> Recognize<F, Match<Self, Self>, T> for Partial<I>
+where
+    I: Fragment,
+    F: FnOnce(Self) -> R,
+    R: Into<Match<T, Self>>,
+{
+    fn recognize(self, f: F) -> Match<Self, Self> {
+        f(self).into().transform(|_, rest| {
+            let consumed: Option<(Self, Self)> = self
+                .length()
+                .checked_sub(rest.length())
+                .and_then(|consumed| self.split(consumed));
+
+            if let Some((consumed, _)) = consumed {
+                TransformMatch::Full(consumed, rest)
+            } else {
+                TransformMatch::Failed
+            }
+        })
+    }
+}
@@ -0,0 +1,515 @@
+#[cfg(feature = "std")]
+use crate::traits::MatchKeywords;
+use crate::{
+    input::Fragment,
+    result::{Expected, Found, MappedMatch, Match, MatchFailed, Snippet, TransformMatch},
+    traits::{
+        MatchBinary, MatchFail, MatchNumber, MatchStatic, MatchStaticIgnoreCase, MatchString,
+        MatchUntil, MatchWith, MatchWithInRange, Recognize, Repetition, Sequence,
+    },
+};
+
+/// Describes which characters are skipped as whitespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Spacing {
+    /// No characters are skipped.
+    None,
+    /// Spaces and horizontal tabs are skipped, which keeps line breaks significant.
+    Horizontal,
+    /// ASCII whitespace is skipped.
+    Ascii,
+    /// Unicode whitespace is skipped in case of `&str`, while `&[u8]` is limited to ASCII whitespace.
+    Unicode,
+}
+
+impl Spacing {
+    /// Returns one if the passed byte is whitespace, and zero otherwise.
+    pub(super) fn byte(self, byte: u8) -> usize {
+        let skipped: bool = match self {
+            Self::None => false,
+            Self::Horizontal => byte == b' ' || byte == b'\t',
+            Self::Ascii | Self::Unicode => byte.is_ascii_whitespace(),
+        };
+
+        usize::from(skipped)
+    }
+}
+
+/// Describes the "trivia" skipped before each token by [`Skipping`]: whitespace and comments.
+/// ## Notes
+/// Line comments extend up to the following line break, which isn't part of them.
+/// Block comments must be closed, otherwise matching the following token fails at the start of the comment.
+/// Empty comment markers are ignored.
+///
+/// [`Skipping`]: struct.Skipping.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Trivia<'a> {
+    spacing: Spacing,
+    line: &'a [&'a str],
+    block: &'a [(&'a str, &'a str)],
+    nested: bool,
+}
+
+impl<'a> Trivia<'a> {
+    /// Constructs a new instance, describing ASCII whitespace without any comments.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            spacing: Spacing::Ascii,
+            line: &[],
+            block: &[],
+            nested: false,
+        }
+    }
+
+    /// Constructs a new instance, describing trivia as defined by C: ASCII whitespace,
+    /// `//` line comments and `/* */` block comments, which don't nest.
+    #[must_use]
+    pub const fn c() -> Self {
+        Self::new()
+            .line_comments(&["//"])
+            .block_comments(&[("/*", "*/")])
+    }
+
+    /// Constructs a new instance, describing trivia as defined by Rust: Unicode whitespace,
+    /// `//` line comments and `/* */` block comments, which nest.
+    #[must_use]
+    pub const fn rust() -> Self {
+        Self::c().spacing(Spacing::Unicode).nested()
+    }
+
+    /// Returns an instance that skips the passed class of whitespace.
+    #[must_use]
+    pub const fn spacing(mut self, spacing: Spacing) -> Self {
+        self.spacing = spacing;
+
+        self
+    }
+
+    /// Returns an instance that skips line comments starting with any of the passed markers.
+    #[must_use]
+    pub const fn line_comments(mut self, markers: &'a [&'a str]) -> Self {
+        self.line = markers;
+
+        self
+    }
+
+    /// Returns an instance that skips block comments enclosed in any of the passed pairs of markers.
+    #[must_use]
+    pub const fn block_comments(mut self, markers: &'a [(&'a str, &'a str)]) -> Self {
+        self.block = markers;
+
+        self
+    }
+
+    /// Returns an instance whose block comments nest, so each opening marker within a comment
+    /// has to be closed before the comment itself.
+    #[must_use]
+    pub const fn nested(mut self) -> Self {
+        self.nested = true;
+
+        self
+    }
+
+    /// Returns the length of the trivia at the start of the fragment.
+    fn length<I>(&self, fragment: I) -> Result<usize, MatchFailed>
+    where
+        I: Fragment,
+        I::Element: Copy + Into<char>,
+    {
+        let mut index: usize = 0;
+
+        loop {
+            let whitespace: usize = fragment.whitespace(index, self.spacing);
+
+            if whitespace != 0 {
+                index += whitespace;
+            } else if self
+                .line
+                .iter()
+                .any(|marker| starts(fragment, index, marker).is_some())
+            {
+                while let Some((c, length)) = fragment.character(index) {
+                    if c == '\n' {
+                        break;
+                    }
+
+                    index += length;
+                }
+            } else if let Some(&(open, close)) = self
+                .block
+                .iter()
+                .find(|(open, _)| starts(fragment, index, open).is_some())
+            {
+                // More input may close the comment, in case of partial inputs.
+                index = self
+                    .block_end(fragment, index, open, close)
+                    .ok_or_else(|| {
+                        fragment.incomplete(MatchFailed::new(
+                            fragment.length() - index,
+                            Expected::Text(Snippet::new(close)),
+                            Found::EndOfInput,
+                        ))
+                    })?;
+            } else {
+                return Ok(index);
+            }
+        }
+    }
+
+    /// Returns the index right after the block comment at the passed index, or `None` if it isn't closed.
+    fn block_end<I>(&self, fragment: I, index: usize, open: &str, close: &str) -> Option<usize>
+    where
+        I: Fragment,
+        I::Element: Copy + Into<char>,
+    {
+        let mut index: usize = starts(fragment, index, open)?;
+        let mut depth: usize = 1;
+
+        while let Some((_, length)) = fragment.character(index) {
+            if let Some(end) = starts(fragment, index, close) {
+                index = end;
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(index);
+                }
+            } else if let Some(end) = starts(fragment, index, open).filter(|_| self.nested) {
+                index = end;
+                depth += 1;
+            } else {
+                index += length;
+            }
+        }
+
+        None
+    }
+}
+
+impl Default for Trivia<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the index right after the passed (non-empty) marker, if the fragment contains it at the passed index.
+fn starts<I>(fragment: I, index: usize, marker: &str) -> Option<usize>
+where
+    I: Fragment,
+    I::Element: Copy + Into<char>,
+{
+    if marker.is_empty() {
+        return None;
+    }
+
+    marker.chars().try_fold(index, |index, expected| {
+        fragment
+            .character(index)
+            .filter(|&(c, _)| c == expected)
+            .map(|(_, length)| index + length)
+    })
+}
+
+/// Wraps an input (`&str` or `&[u8]`), skipping the described [`Trivia`] before each matched token.
+/// ## Notes
+/// Both "matched" and "rest" parts produced by matching a wrapped input are wrapped too,
+/// while the "matched" parts don't include the skipped trivia.
+/// Skipping can be switched off, e.g. within string literals, by using the [`verbatim`] method,
+/// and switched back on by using the [`skipping`] method.
+/// Trivia at the end of the input is left in place, unless it's matched by the [`match_trivia`] method.
+/// The wrapped input may itself be wrapped, e.g. `Skipping<Located<&str>>` locates the matched tokens.
+///
+/// [`Trivia`]: struct.Trivia.html
+/// [`verbatim`]: #method.verbatim
+/// [`skipping`]: #method.skipping
+/// [`match_trivia`]: #method.match_trivia
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Skipping<'a, I> {
+    fragment: I,
+    trivia: Trivia<'a>,
+    verbatim: bool,
+}
+
+impl<'a, I> Skipping<'a, I> {
+    /// Constructs a new instance, skipping the passed trivia.
+    pub const fn new(fragment: I, trivia: Trivia<'a>) -> Self {
+        Self {
+            fragment,
+            trivia,
+            verbatim: false,
+        }
+    }
+
+    /// Returns a reference to the wrapped input.
+    pub const fn fragment(&self) -> &I {
+        &self.fragment
+    }
+
+    /// Returns the wrapped input, consuming the object.
+    pub fn into_fragment(self) -> I {
+        self.fragment
+    }
+
+    /// Returns the skipped trivia.
+    pub const fn trivia(&self) -> Trivia<'a> {
+        self.trivia
+    }
+
+    /// Returns an instance that doesn't skip trivia, until switched back by the [`skipping`] method.
+    ///
+    /// [`skipping`]: #method.skipping
+    #[must_use]
+    pub fn verbatim(mut self) -> Self {
+        self.verbatim = true;
+
+        self
+    }
+
+    /// Returns an instance that skips trivia.
+    #[must_use]
+    pub fn skipping(mut self) -> Self {
+        self.verbatim = false;
+
+        self
+    }
+
+    /// Returns boolean indicating whether skipping trivia is switched off.
+    pub const fn is_verbatim(&self) -> bool {
+        self.verbatim
+    }
+
+    fn wrap(&self, fragment: I) -> Self {
+        Self {
+            fragment,
+            trivia: self.trivia,
+            verbatim: self.verbatim,
+        }
+    }
+}
+
+impl<I> Skipping<'_, I>
+where
+    I: Fragment,
+    I::Element: Copy + Into<char>,
+{
+    /// Matches the trivia at the start of the input, which may be empty.
+    /// Skipping trivia this way isn't affected by the [`verbatim`] method.
+    ///
+    /// [`verbatim`]: #method.verbatim
+    pub fn match_trivia(self) -> Match<Self, Self> {
+        match self
+            .trivia
+            .length(self.fragment)
+            .map(|length| self.split(length))
+        {
+            Ok(Some((matched, rest))) => Match::new(Some(matched), rest),
+            Ok(None) => Match::failed(),
+            Err(error) => Match::failed_with(error),
+        }
+    }
+
+    /// Returns the input following the trivia, unless skipping is switched off.
+    fn token(&self) -> Result<I, MatchFailed> {
+        if self.verbatim {
+            Ok(self.fragment)
+        } else {
+            let length: usize = self.trivia.length(self.fragment)?;
+
+            self.fragment
+                .split(length)
+                .map(|(_, rest)| rest)
+                .ok_or_else(MatchFailed::default)
+        }
+    }
+
+    fn skip<F>(self, f: F) -> Match<Self, Self>
+    where
+        F: FnOnce(I) -> Match<I, I>,
+    {
+        match self.token().map(f).and_then(Match::take) {
+            Ok((matched, rest)) => Match::new(matched.map(|m| self.wrap(m)), self.wrap(rest)),
+            Err(error) => Match::failed_with(error),
+        }
+    }
+
+    fn skip_mapped<F, V>(self, f: F) -> MappedMatch<Self, Self, V>
+    where
+        F: FnOnce(I) -> MappedMatch<I, I, V>,
+    {
+        match self.token().map(f).and_then(MappedMatch::take) {
+            Ok((matched, rest)) => MappedMatch::new(
+                matched.map(|(m, value)| (self.wrap(m), value)),
+                self.wrap(rest),
+            ),
+            Err(error) => MappedMatch::failed_with(error),
+        }
+    }
+}
+
+impl<I> Fragment for Skipping<'_, I>
+where
+    I: Fragment,
+{
+    type Element = I::Element;
+
+    fn length(self) -> usize {
+        self.fragment.length()
+    }
+
+    fn count(self, bytes: bool) -> usize {
+        self.fragment.count(bytes)
+    }
+
+    fn split(self, at: usize) -> Option<(Self, Self)> {
+        self.fragment
+            .split(at)
+            .map(|(before, after)| (self.wrap(before), self.wrap(after)))
+    }
+
+    fn character(self, index: usize) -> Option<(char, usize)>
+    where
+        I::Element: Copy + Into<char>,
+    {
+        self.fragment.character(index)
+    }
+
+    fn whitespace(self, index: usize, spacing: Spacing) -> usize
+    where
+        I::Element: Copy + Into<char>,
+    {
+        self.fragment.whitespace(index, spacing)
+    }
+    fn incomplete(self, error: MatchFailed) -> MatchFailed {
+        self.fragment.incomplete(error)
+    }
+}
+
+impl<I> Sequence for Skipping<'_, I> where I: Sequence {}
+
+impl<F, V, I> MatchBinary<F, MappedMatch<Self, Self, V>> for Skipping<'_, I>
+where
+    I: Fragment + MatchBinary<F, MappedMatch<I, I, V>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_binary(self, format: F) -> MappedMatch<Self, Self, V> {
+        self.skip_mapped(|token| token.match_binary(format))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, I> MatchKeywords<K, MappedMatch<Self, Self, V>> for Skipping<'_, I>
+where
+    I: Fragment + MatchKeywords<K, MappedMatch<I, I, V>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_keywords(self, keywords: K) -> MappedMatch<Self, Self, V> {
+        self.skip_mapped(|token| token.match_keywords(keywords))
+    }
+}
+
+impl<F, N, I> MatchNumber<F, MappedMatch<Self, Self, N>> for Skipping<'_, I>
+where
+    I: Fragment + MatchNumber<F, MappedMatch<I, I, N>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_number(self, format: F) -> MappedMatch<Self, Self, N> {
+        self.skip_mapped(|token| token.match_number(format))
+    }
+}
+
+impl<E, T, I> MatchStatic<E, T, Match<Self, Self>> for Skipping<'_, I>
+where
+    I: Fragment + MatchStatic<E, T, Match<I, I>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_static(self, pattern: T) -> Match<Self, Self> {
+        self.skip(|token| token.match_static(pattern))
+    }
+}
+
+impl<E, T, I> MatchStaticIgnoreCase<E, T, Match<Self, Self>> for Skipping<'_, I>
+where
+    I: Fragment + MatchStaticIgnoreCase<E, T, Match<I, I>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_static_ignore_case(self, pattern: T) -> Match<Self, Self> {
+        self.skip(|token| token.match_static_ignore_case(pattern))
+    }
+}
+
+impl<F, V, I> MatchString<F, MappedMatch<Self, Self, V>> for Skipping<'_, I>
+where
+    I: Fragment + MatchString<F, MappedMatch<I, I, V>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_string(self, format: F) -> MappedMatch<Self, Self, V> {
+        self.skip_mapped(|token| token.match_string(format))
+    }
+}
+
+impl<E, T, I> MatchUntil<E, T, Match<Self, Self>> for Skipping<'_, I>
+where
+    I: Fragment + MatchUntil<E, T, Match<I, I>>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_until(self, pattern: T) -> Match<Self, Self> {
+        self.skip(|token| token.match_until(pattern))
+    }
+
+    fn match_until_including(self, pattern: T) -> Match<Self, Self> {
+        self.skip(|token| token.match_until_including(pattern))
+    }
+
+    fn match_until_found(self, pattern: T) -> Match<Self, Self> {
+        self.skip(|token| token.match_until_found(pattern))
+    }
+}
+
+impl<F, H, I> MatchWith<F, Match<Self, Self>, H> for Skipping<'_, I>
+where
+    I: Fragment + MatchWith<F, Match<I, I>, H>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_with(self, pattern: F) -> Match<Self, Self> {
+        self.skip(|token| token.match_with(pattern))
+    }
+}
+
+impl<F, H1, H2, I> MatchWithInRange<F, Match<Self, Self>, H1, H2> for Skipping<'_, I>
+where
+    I: Fragment + MatchWithInRange<F, Match<I, I>, H1, H2>,
+    I::Element: Copy + Into<char>,
+{
+    fn match_range_with<B>(self, range: B, pattern: F) -> Match<Self, Self>
+    where
+        B: Repetition,
+    {
+        self.skip(|token| token.match_range_with(range, pattern))
+    }
+}
+
+impl<F, R, T, I> Recognize<F, Match<Self, Self>, T> for Skipping<'_, I>
+where
+    I: Fragment,
+    I::Element: Copy + Into<char>,
+    F: FnOnce(Self) -> R,
+    R: Into<Match<T, Self>>,
+{
+    fn recognize(self, f: F) -> Match<Self, Self> {
+        // The consumed part starts after the trivia, as the "matched" parts of tokens do.
+        let token: I = match self.token() {
+            Ok(token) => token,
+            Err(error) => return Match::failed_with(error),
+        };
+
+        f(self).into().transform(|_, rest| {
+            let consumed: usize = token.length().saturating_sub(rest.fragment.length());
+
+            if let Some((consumed, _)) = token.split(consumed) {
+                TransformMatch::Full(self.wrap(consumed), rest)
+            } else {
+                TransformMatch::Failed
+            }
+        })
+    }
+}
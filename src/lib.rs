@@ -13,8 +13,10 @@
 //!     * Opt-out of this feature to use limited version relying only on `libcore`.
 //!     * **Note**: Opting-out will limit some functionalities.
 //! * `no_track_caller`
-//!     * Disables the `#[track_caller]` attributes within the library.
-//!     * This is required for compilation below version 1.46 of Rust.
+//!     * Has no effect and is kept for compatibility, as the minimum supported version has stable `#[track_caller]`.
+//!
+//! # Minimum supported Rust version
+//! Rust 1.73 or newer is required.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(
@@ -23,32 +25,72 @@
     missing_docs,
     unsafe_code,
     clippy::all,
-    clippy::pedantic,
-    clippy::cargo
+    // The `clippy::cargo` group without `negative_feature_names`, which rejects the `no_track_caller` feature.
+    clippy::cargo_common_metadata,
+    clippy::multiple_crate_versions,
+    clippy::redundant_feature_names,
+    clippy::wildcard_dependencies
 )]
+#![cfg_attr(not(test), forbid(clippy::pedantic))]
+#![cfg_attr(test, deny(clippy::pedantic))]
 
 /// This module re-exports all essential types and all (public) traits.
 ///
 /// Traits that are inaccessible are used only for implementations, leaving traits free for new implementations.
 pub mod prelude {
-    pub use crate::result::{Match, MatchFailed};
+    pub use crate::input::{Fragment, Located, Location, Partial, Skipping, Trivia};
+
+    pub use crate::parser::Parser;
+
+    pub use crate::predicate::Predicate;
+
+    pub use crate::result::{Expected, Found, Match, MatchFailed, Needed};
 
     pub use crate::traits::*;
 }
 
+pub mod input;
+pub mod parser;
+pub mod predicate;
 pub mod result;
 pub mod traits;
 
 #[cfg(test)]
+// The original tests predate these lints.
+#[allow(clippy::ref_option_ref, clippy::should_panic_without_expect)]
 mod tests {
     mod discarding;
+    mod located;
+    mod lookahead;
     mod match_alternatives;
+    mod match_binary;
+    mod match_failed;
     mod match_mapped_alternatives;
+    mod match_number;
     mod match_static;
+    mod match_static_ignore_case;
+    mod match_string;
+    mod match_until;
     mod match_with;
+    mod match_with_in_range;
+    mod parser;
+    mod partial;
+    mod predicate;
+    mod recursive;
+    mod sequence;
+    mod trivia;
+    mod wrappers;
 
     #[cfg(feature = "std")]
     mod std {
         mod collecting_match;
+        mod keywords;
+        mod match_alternatives;
+        mod match_number;
+        mod match_string;
+        mod panic_free;
+        mod parser;
+        mod random;
+        mod separated;
     }
 }
@@ -0,0 +1,160 @@
+//! This module holds the [`Parser`] trait, which allows grammar rules to be named, stored and reused.
+//!
+//! ## Adapters
+//! Parsers are plugged into the match results' combinators by using the adapter functions:
+//! * [`on_input`] - for combinators that pass only the input, like [`AlternativesMatch::add_path`].
+//! * [`on_rest`] - for combinators that pass the "matched" part by value, like [`Match::discarding`].
+//! * [`on_collected_rest`] - for combinators of [`CollectingMatch`], like [`CollectingMatch::single`].
+//!
+//! [`Parser`]: trait.Parser.html
+//! [`on_input`]: fn.on_input.html
+//! [`on_rest`]: fn.on_rest.html
+//! [`on_collected_rest`]: fn.on_collected_rest.html
+//! [`CollectingMatch`]: ../result/struct.CollectingMatch.html
+//! [`AlternativesMatch::add_path`]: ../result/struct.AlternativesMatch.html#method.add_path
+//! [`Match::discarding`]: ../result/struct.Match.html#method.discarding
+//! [`CollectingMatch::single`]: ../result/struct.CollectingMatch.html#method.single
+
+use core::marker::PhantomData;
+
+use crate::traits::{MatchStatic, MatchWith};
+
+mod binary;
+pub use binary::*;
+
+#[cfg(feature = "std")]
+mod keywords;
+#[cfg(feature = "std")]
+pub use keywords::*;
+
+mod number;
+pub use number::*;
+
+mod recursive;
+pub use recursive::*;
+
+mod string;
+pub use string::*;
+
+mod varint;
+pub use varint::*;
+
+/// Provides interface for reusable matching rules.
+/// ## Inplementation & usage
+/// The third parameter is a helper parameter which defaults to `()`.
+/// It can be used to implement overloading by saving the function parameters, for example.
+pub trait Parser<I, R, H = ()> {
+    /// Matches the rule against the passed input.
+    fn parse(&mut self, input: I) -> R;
+}
+
+impl<I, R, F> Parser<I, R> for F
+where
+    F: FnMut(I) -> R,
+{
+    fn parse(&mut self, input: I) -> R {
+        self(input)
+    }
+}
+
+/// Parser that matches a single "static" pattern.
+/// See [`MatchStatic`].
+///
+/// [`MatchStatic`]: ../traits/trait.MatchStatic.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Static<T> {
+    pattern: T,
+}
+
+impl<T> Static<T> {
+    /// Constructs a new instance.
+    pub const fn new(pattern: T) -> Self {
+        Self { pattern }
+    }
+}
+
+impl<E, T, R, I> Parser<I, R, Static<E>> for Static<T>
+where
+    T: Clone,
+    I: MatchStatic<E, T, R>,
+{
+    fn parse(&mut self, input: I) -> R {
+        input.match_static(self.pattern.clone())
+    }
+}
+
+/// Parser that matches a single "dynamic" pattern, described by a predicate.
+/// See [`MatchWith`].
+///
+/// [`MatchWith`]: ../traits/trait.MatchWith.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct With<F> {
+    pattern: F,
+}
+
+impl<F> With<F> {
+    /// Constructs a new instance.
+    pub const fn new(pattern: F) -> Self {
+        Self { pattern }
+    }
+}
+
+impl<F, R, H, I> Parser<I, R, With<H>> for With<F>
+where
+    F: Clone,
+    I: MatchWith<F, R, H>,
+{
+    fn parse(&mut self, input: I) -> R {
+        input.match_with(self.pattern.clone())
+    }
+}
+
+/// Parser that borrows another parser, allowing it to be reused afterwards.
+impl<I, R, H, P> Parser<I, R, ByRef<H>> for &mut P
+where
+    P: Parser<I, R, H>,
+{
+    fn parse(&mut self, input: I) -> R {
+        (**self).parse(input)
+    }
+}
+
+/// Helper type used to tell apart implementations of [`Parser`] for references.
+///
+/// [`Parser`]: trait.Parser.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByRef<H>(PhantomData<H>);
+
+/// Adapts a parser for combinators that pass only the input, like [`AlternativesMatch::add_path`].
+///
+/// [`AlternativesMatch::add_path`]: ../result/struct.AlternativesMatch.html#method.add_path
+pub fn on_input<I, R, H, P>(mut parser: P) -> impl FnMut(I) -> R
+where
+    P: Parser<I, R, H>,
+{
+    move |input: I| parser.parse(input)
+}
+
+/// Adapts a parser for combinators that pass the "matched" part by value along with the "rest" part,
+/// like [`Match::discarding`] and [`Match::optional`]. The "matched" part is ignored.
+///
+/// [`Match::discarding`]: ../result/struct.Match.html#method.discarding
+/// [`Match::optional`]: ../result/struct.Match.html#method.optional
+pub fn on_rest<T, I, R, H, P>(mut parser: P) -> impl FnMut(T, I) -> R
+where
+    P: Parser<I, R, H>,
+{
+    move |_: T, input: I| parser.parse(input)
+}
+
+/// Adapts a parser for the combinators of [`CollectingMatch`], like [`CollectingMatch::single`].
+/// The last collected "matched" part is ignored.
+///
+/// [`CollectingMatch`]: ../result/struct.CollectingMatch.html
+/// [`CollectingMatch::single`]: ../result/struct.CollectingMatch.html#method.single
+pub fn on_collected_rest<T, I, R, H, P>(mut parser: P) -> impl FnMut(Option<&T>, I) -> R
+where
+    P: Parser<I, R, H>,
+{
+    move |_: Option<&T>, input: I| parser.parse(input)
+}
@@ -0,0 +1,112 @@
+use core::marker::PhantomData;
+
+use crate::{parser::Parser, traits::MatchBinary};
+
+mod private {
+    use core::convert::TryFrom;
+
+    use super::Endian;
+
+    pub trait Fixed: Copy {
+        /// Size of the value in bytes.
+        const SIZE: usize;
+
+        /// Decodes the value from the first bytes of the input, unless it's too short.
+        fn read(input: &[u8], endian: Endian) -> Option<Self>;
+    }
+
+    macro_rules! impl_fixed {
+        ($($t: ty),+ $(,)?) => {
+            $(
+                impl Fixed for $t {
+                    const SIZE: usize = core::mem::size_of::<$t>();
+
+                    fn read(input: &[u8], endian: Endian) -> Option<Self> {
+                        let bytes: [u8; Self::SIZE] = <[u8; Self::SIZE]>::try_from(input.get(..Self::SIZE)?).ok()?;
+
+                        Some(match endian {
+                            Endian::Big => <$t>::from_be_bytes(bytes),
+                            Endian::Little => <$t>::from_le_bytes(bytes),
+                        })
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_fixed!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+}
+
+pub(crate) use private::Fixed;
+
+/// Order of bytes within binary values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Endian {
+    /// The most significant byte comes first.
+    Big,
+    /// The least significant byte comes first.
+    Little,
+}
+
+impl Endian {
+    /// Byte order of the target platform.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Self = Self::Big;
+
+    /// Byte order of the target platform.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Self = Self::Little;
+}
+
+/// Format of a fixed-width binary value, matched by [`MatchBinary::match_binary`] as a value of type `N`.
+/// ## Notes
+/// Integers from `u8` to `u128` and from `i8` to `i128` are supported, as well as `f32` and `f64`.
+/// The byte order can be chosen at runtime, e.g. after reading a byte order mark, by using [`Binary::new`].
+///
+/// [`MatchBinary::match_binary`]: ../traits/trait.MatchBinary.html#tymethod.match_binary
+/// [`Binary::new`]: #method.new
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Binary<N> {
+    endian: Endian,
+    number: PhantomData<N>,
+}
+
+impl<N> Binary<N> {
+    /// Constructs a new instance, describing a value in the passed byte order.
+    #[must_use]
+    pub const fn new(endian: Endian) -> Self {
+        Self {
+            endian,
+            number: PhantomData,
+        }
+    }
+
+    /// Constructs a new instance, describing a big-endian value.
+    #[must_use]
+    pub const fn big() -> Self {
+        Self::new(Endian::Big)
+    }
+
+    /// Constructs a new instance, describing a little-endian value.
+    #[must_use]
+    pub const fn little() -> Self {
+        Self::new(Endian::Little)
+    }
+
+    /// Returns the byte order of the value.
+    #[must_use]
+    pub const fn endian(&self) -> Endian {
+        self.endian
+    }
+}
+
+/// Parser that matches a binary value of the described format.
+impl<N, I, R> Parser<I, R, Binary<N>> for Binary<N>
+where
+    Self: Copy,
+    I: MatchBinary<Self, R>,
+{
+    fn parse(&mut self, input: I) -> R {
+        input.match_binary(*self)
+    }
+}
@@ -0,0 +1,222 @@
+use std::{collections::VecDeque, iter::FromIterator};
+
+use crate::{parser::Parser, traits::MatchKeywords};
+
+#[derive(Debug, Clone)]
+struct Node<E, V> {
+    children: Vec<(E, usize)>,
+    value: Option<V>,
+}
+
+impl<E, V> Default for Node<E, V> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            value: None,
+        }
+    }
+}
+
+/// Outcome of walking the keyword set along an input.
+pub(crate) struct Search<'a, V> {
+    /// The length and the value of the longest accepted keyword.
+    pub(crate) longest: Option<(usize, &'a V)>,
+    /// The amount of elements walked along.
+    pub(crate) depth: usize,
+    /// The index of the node reached by walking along.
+    pub(crate) node: usize,
+    /// Whether the input ended while longer keywords were still possible.
+    pub(crate) exhausted: bool,
+}
+
+/// Prebuilt set of "static" patterns (keywords), each associated with a value.
+/// See [`MatchKeywords`].
+/// ## Notes
+/// The set is stored as a prefix tree, so the longest keyword at the start of the input is found in a
+/// single pass over it, instead of trying each keyword in turn.
+/// Keyword sets for `&str` inputs are built from elements of type `u8`, as in `Keywords::indexed(["select", "set"])`.
+///
+/// [`MatchKeywords`]: ../traits/trait.MatchKeywords.html
+#[derive(Debug, Clone)]
+pub struct Keywords<E, V = usize> {
+    nodes: Vec<Node<E, V>>,
+}
+
+impl<E, V> Keywords<E, V> {
+    /// Constructs a new, empty instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::default()],
+        }
+    }
+
+    /// Returns boolean indicating whether the set holds no keywords.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.iter().all(|node| node.value.is_none())
+    }
+
+    /// Adds a keyword, associated with the passed value.
+    /// Adding the same keyword again replaces its value.
+    #[must_use]
+    pub fn add<P>(mut self, pattern: P, value: V) -> Self
+    where
+        E: Clone + Eq,
+        P: AsRef<[E]>,
+    {
+        self.insert(pattern.as_ref(), value);
+
+        self
+    }
+
+    fn insert(&mut self, pattern: &[E], value: V)
+    where
+        E: Clone + Eq,
+    {
+        let mut current: usize = 0;
+
+        for element in pattern {
+            let next: Option<usize> = self.nodes[current]
+                .children
+                .iter()
+                .find(|(child, _)| child == element)
+                .map(|&(_, index)| index);
+
+            current = next.unwrap_or_else(|| {
+                let index: usize = self.nodes.len();
+
+                self.nodes.push(Node::default());
+                self.nodes[current].children.push((element.clone(), index));
+
+                index
+            });
+        }
+
+        self.nodes[current].value = Some(value);
+    }
+
+    /// Walks the set along the passed elements, remembering the longest keyword whose length is accepted.
+    pub(crate) fn search<'a, I, A>(&self, elements: I, accept: A) -> Search<'_, V>
+    where
+        E: 'a + Eq,
+        I: IntoIterator<Item = &'a E>,
+        A: Fn(usize) -> bool,
+    {
+        let mut elements = elements.into_iter();
+
+        let mut current: usize = 0;
+        let mut node: &Node<E, V> = &self.nodes[current];
+        let mut depth: usize = 0;
+        let mut longest: Option<(usize, &V)> = node.value.as_ref().map(|value| (0, value));
+
+        while !node.children.is_empty() {
+            let element: &E = if let Some(element) = elements.next() {
+                element
+            } else {
+                return Search {
+                    longest,
+                    depth,
+                    node: current,
+                    exhausted: true,
+                };
+            };
+
+            if let Some(&(_, index)) = node.children.iter().find(|(child, _)| child == element) {
+                current = index;
+                node = &self.nodes[current];
+                depth += 1;
+
+                if let Some(value) = node.value.as_ref().filter(|_| accept(depth)) {
+                    longest = Some((depth, value));
+                }
+            } else {
+                break;
+            }
+        }
+
+        Search {
+            longest,
+            depth,
+            node: current,
+            exhausted: false,
+        }
+    }
+
+    /// Returns the elements that complete the shortest keyword reachable from the passed node.
+    /// Returns `None` when no keyword is reachable.
+    pub(crate) fn completion(&self, node: usize) -> Option<Vec<&E>> {
+        let mut queue: VecDeque<(usize, Vec<&E>)> = VecDeque::new();
+
+        queue.push_back((node, Vec::new()));
+
+        while let Some((index, path)) = queue.pop_front() {
+            let node: &Node<E, V> = &self.nodes[index];
+
+            if node.value.is_some() {
+                return Some(path);
+            }
+
+            for (element, child) in &node.children {
+                let mut path: Vec<&E> = path.clone();
+
+                path.push(element);
+
+                queue.push_back((*child, path));
+            }
+        }
+
+        None
+    }
+}
+
+impl<E> Keywords<E> {
+    /// Constructs a new instance, associating each keyword with its index.
+    pub fn indexed<I>(patterns: I) -> Self
+    where
+        E: Clone + Eq,
+        I: IntoIterator,
+        I::Item: AsRef<[E]>,
+    {
+        patterns
+            .into_iter()
+            .enumerate()
+            .map(|(index, pattern)| (pattern, index))
+            .collect()
+    }
+}
+
+impl<E, V> Default for Keywords<E, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, V, P> FromIterator<(P, V)> for Keywords<E, V>
+where
+    E: Clone + Eq,
+    P: AsRef<[E]>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (P, V)>,
+    {
+        let mut keywords: Self = Self::new();
+
+        for (pattern, value) in iter {
+            keywords.insert(pattern.as_ref(), value);
+        }
+
+        keywords
+    }
+}
+
+/// Parser that matches the longest keyword of the set.
+impl<'a, E, V, I, R> Parser<I, R, Keywords<E, V>> for &'a Keywords<E, V>
+where
+    I: MatchKeywords<&'a Keywords<E, V>, R>,
+{
+    fn parse(&mut self, input: I) -> R {
+        input.match_keywords(self)
+    }
+}
@@ -0,0 +1,784 @@
+use core::{convert::TryFrom, marker::PhantomData};
+
+use crate::{parser::Parser, traits::MatchNumber};
+
+mod private {
+    use core::{convert::TryFrom, ops::Neg, str::FromStr};
+
+    use crate::result::{Expected, Found, MatchFailed};
+
+    /// Reason for which matching a number failed.
+    pub enum NumberError {
+        /// A digit in the given radix was expected at the given offset.
+        Digit(usize, u32),
+        /// The number doesn't fit within its type.
+        Overflow,
+    }
+
+    impl NumberError {
+        /// Converts into a failure of matching the passed amount of elements,
+        /// describing the element found at the offset by using the passed function.
+        pub fn into_failure<F>(self, length: usize, found: F) -> MatchFailed
+        where
+            F: FnOnce(usize) -> Found,
+        {
+            match self {
+                Self::Digit(offset, radix) => MatchFailed::new(
+                    length.saturating_sub(offset),
+                    Expected::Digit(radix),
+                    if offset < length {
+                        found(offset)
+                    } else {
+                        Found::EndOfInput
+                    },
+                ),
+                Self::Overflow => MatchFailed::new(length, Expected::Number, Found::Overflow),
+            }
+        }
+    }
+
+    /// Describes how to read the literal at the start of the input.
+    pub trait Format: Copy {
+        type Value;
+
+        /// Returns the length of the literal, along with its value.
+        fn read(self, input: &[u8]) -> Result<(usize, Self::Value), NumberError>;
+    }
+
+    pub trait Integral: Copy + TryFrom<u32> {
+        const SIGNED: bool;
+
+        const ZERO: Self;
+
+        fn checked_mul(self, other: Self) -> Option<Self>;
+
+        fn checked_add(self, other: Self) -> Option<Self>;
+
+        fn checked_sub(self, other: Self) -> Option<Self>;
+    }
+
+    macro_rules! impl_integral {
+        ($($t: ty),+ $(,)?) => {
+            $(
+                impl Integral for $t {
+                    const SIGNED: bool = <$t>::MIN != 0;
+
+                    const ZERO: Self = 0;
+
+                    fn checked_mul(self, other: Self) -> Option<Self> {
+                        <$t>::checked_mul(self, other)
+                    }
+
+                    fn checked_add(self, other: Self) -> Option<Self> {
+                        <$t>::checked_add(self, other)
+                    }
+
+                    fn checked_sub(self, other: Self) -> Option<Self> {
+                        <$t>::checked_sub(self, other)
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_integral! {
+        i8, i16, i32, i64, i128, isize,
+        u8, u16, u32, u64, u128, usize,
+    }
+
+    pub trait Floating: Copy + FromStr + Neg<Output = Self> {
+        const INFINITY: Self;
+
+        const NAN: Self;
+
+        /// Amount of significant bits, including the implicit one.
+        const PRECISION: u32;
+
+        /// Binary exponent of the smallest normal value.
+        const MIN_EXPONENT: i64;
+
+        /// Binary exponent of the largest finite value.
+        const MAX_EXPONENT: i64;
+
+        fn from_bits(bits: u64) -> Self;
+    }
+
+    impl Floating for f32 {
+        const INFINITY: Self = Self::INFINITY;
+
+        const NAN: Self = Self::NAN;
+
+        const PRECISION: u32 = Self::MANTISSA_DIGITS;
+
+        const MIN_EXPONENT: i64 = -126;
+
+        const MAX_EXPONENT: i64 = 127;
+
+        fn from_bits(bits: u64) -> Self {
+            u32::try_from(bits).map_or(Self::NAN, Self::from_bits)
+        }
+    }
+
+    impl Floating for f64 {
+        const INFINITY: Self = Self::INFINITY;
+
+        const NAN: Self = Self::NAN;
+
+        const PRECISION: u32 = Self::MANTISSA_DIGITS;
+
+        const MIN_EXPONENT: i64 = -1022;
+
+        const MAX_EXPONENT: i64 = 1023;
+
+        fn from_bits(bits: u64) -> Self {
+            Self::from_bits(bits)
+        }
+    }
+}
+
+use private::{Floating, Integral};
+
+pub(crate) use private::{Format, NumberError};
+
+/// Format of an integer literal, matched by [`MatchNumber::match_number`] as a value of type `N`.
+/// ## Notes
+/// By default, the literal is decimal and may start with a sign: either `+` or, for signed types, `-`.
+/// A literal that doesn't fit within `N` fails to match, with the failure positioned at its start.
+///
+/// [`MatchNumber::match_number`]: ../traits/trait.MatchNumber.html#tymethod.match_number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Integer<N> {
+    radix: u32,
+    prefixes: bool,
+    separators: bool,
+    sign: bool,
+    number: PhantomData<N>,
+}
+
+impl<N> Integer<N> {
+    /// Constructs a new instance, describing a decimal literal.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            radix: 10,
+            prefixes: false,
+            separators: false,
+            sign: true,
+            number: PhantomData,
+        }
+    }
+
+    /// Returns an instance describing a literal in the passed radix.
+    /// # Panics
+    /// Panics when the radix isn't within the range from 2 to 36.
+    #[must_use]
+    pub const fn radix(mut self, radix: u32) -> Self {
+        assert!(2 <= radix && radix <= 36, "radix must be within 2..=36");
+
+        self.radix = radix;
+
+        self
+    }
+
+    /// Returns an instance that accepts the radix prefixes `0x`, `0o` and `0b` (in either case),
+    /// which take precedence over the set radix.
+    #[must_use]
+    pub const fn allow_prefixes(mut self) -> Self {
+        self.prefixes = true;
+
+        self
+    }
+
+    /// Returns an instance that accepts `_` as a digit separator anywhere after the first digit.
+    #[must_use]
+    pub const fn allow_separators(mut self) -> Self {
+        self.separators = true;
+
+        self
+    }
+
+    /// Returns an instance that doesn't accept a leading sign.
+    #[must_use]
+    pub const fn forbid_sign(mut self) -> Self {
+        self.sign = false;
+
+        self
+    }
+}
+
+impl<N> Format for Integer<N>
+where
+    N: Integral,
+{
+    type Value = N;
+
+    fn read(self, input: &[u8]) -> Result<(usize, N), NumberError> {
+        let (mut index, negative): (usize, bool) = match input.first() {
+            Some(b'+') if self.sign => (1, false),
+            Some(b'-') if self.sign && N::SIGNED => (1, true),
+            _ => (0, false),
+        };
+
+        let mut radix: u32 = self.radix;
+
+        if let (true, Some(b'0'), Some(prefix)) =
+            (self.prefixes, input.get(index), input.get(index + 1))
+        {
+            let prefixed: Option<u32> = match prefix.to_ascii_lowercase() {
+                b'x' => Some(16),
+                b'o' => Some(8),
+                b'b' => Some(2),
+                _ => None,
+            };
+
+            if let Some(prefixed) = prefixed {
+                radix = prefixed;
+                index += 2;
+            }
+        }
+
+        let base: N = N::try_from(radix).map_err(|_| NumberError::Overflow)?;
+
+        let mut value: N = N::ZERO;
+        let mut digits: usize = 0;
+
+        while let Some(&byte) = input.get(index) {
+            if byte == b'_' && self.separators && digits != 0 {
+                index += 1;
+
+                continue;
+            }
+
+            let digit: N = if let Some(digit) = char::from(byte).to_digit(radix) {
+                N::try_from(digit).map_err(|_| NumberError::Overflow)?
+            } else {
+                break;
+            };
+
+            // Negative values are accumulated downwards, so the minimal value of signed types fits as well.
+            value = value
+                .checked_mul(base)
+                .and_then(|value| {
+                    if negative {
+                        value.checked_sub(digit)
+                    } else {
+                        value.checked_add(digit)
+                    }
+                })
+                .ok_or(NumberError::Overflow)?;
+
+            digits += 1;
+            index += 1;
+        }
+
+        if digits == 0 {
+            Err(NumberError::Digit(index, radix))
+        } else {
+            Ok((index, value))
+        }
+    }
+}
+
+impl<N> Default for Integer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parser that matches an integer literal of the described format.
+impl<N, I, R> Parser<I, R, Integer<N>> for Integer<N>
+where
+    Self: Copy,
+    I: MatchNumber<Self, R>,
+{
+    fn parse(&mut self, input: I) -> R {
+        input.match_number(*self)
+    }
+}
+
+/// Maximal amount of significant decimal digits kept when reading a floating-point literal.
+/// Correct rounding never depends on more than 767 of them, as long as the dropped ones are accounted for.
+const SIGNIFICANT_DIGITS: usize = 800;
+
+/// Describes the syntax accepted by [`Float`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Grammar {
+    Json,
+    Rust,
+    C,
+}
+
+impl Grammar {
+    /// Returns boolean indicating whether a leading `+` sign is accepted, besides the `-` sign.
+    const fn plus(self) -> bool {
+        !matches!(self, Self::Json)
+    }
+
+    /// Returns boolean indicating whether integer parts with leading zeros, like `01.5`, are accepted.
+    const fn leading_zeros(self) -> bool {
+        !matches!(self, Self::Json)
+    }
+
+    /// Returns boolean indicating whether `_` is accepted as a digit separator anywhere after the first digit.
+    const fn separators(self) -> bool {
+        matches!(self, Self::Rust)
+    }
+
+    /// Returns boolean indicating whether literals without an integer part, like `.5`, are accepted.
+    const fn empty_integer(self) -> bool {
+        matches!(self, Self::C)
+    }
+
+    /// Returns boolean indicating whether literals without a fractional part after the point, like `5.`,
+    /// are accepted.
+    const fn empty_fraction(self) -> bool {
+        !matches!(self, Self::Json)
+    }
+
+    /// Returns boolean indicating whether an empty fractional part is rejected when the point is followed
+    /// by another point, an underscore or a letter, like in `5..` and `5.max`.
+    const fn guarded_point(self) -> bool {
+        matches!(self, Self::Rust)
+    }
+
+    /// Returns boolean indicating whether `inf`, `infinity` and `nan` are accepted, in any case.
+    const fn special(self) -> bool {
+        !matches!(self, Self::Json)
+    }
+
+    /// Returns boolean indicating whether hexadecimal literals with an optional binary exponent,
+    /// like `0x1.8p3`, are accepted.
+    const fn hexadecimal(self) -> bool {
+        matches!(self, Self::C)
+    }
+}
+
+/// Format of a floating-point literal, matched by [`MatchNumber::match_number`] as a value of type `N`
+/// (either `f32` or `f64`).
+/// ## Notes
+/// The value is always the correctly rounded (to nearest, ties to even) value of the literal.
+/// Literals without a point or an exponent, like `42`, are accepted by all grammars.
+/// An exponent, like `e-3`, is only matched when it has at least one digit.
+///
+/// [`MatchNumber::match_number`]: ../traits/trait.MatchNumber.html#tymethod.match_number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Float<N> {
+    grammar: Grammar,
+    number: PhantomData<N>,
+}
+
+impl<N> Float<N> {
+    const fn with_grammar(grammar: Grammar) -> Self {
+        Self {
+            grammar,
+            number: PhantomData,
+        }
+    }
+
+    /// Constructs a new instance, describing a number as defined by JSON.
+    /// Only a `-` sign is accepted, and the integer part can't have leading zeros, so `01` is matched as `0`.
+    /// Both the integer and the fractional part (when there is a point) must have digits.
+    #[must_use]
+    pub const fn json() -> Self {
+        Self::with_grammar(Grammar::Json)
+    }
+
+    /// Constructs a new instance, describing a literal like Rust's, along with a sign, infinity and NaN.
+    /// Digits may be separated by `_`, and the fractional part may be empty, like in `5.`,
+    /// unless the point is followed by another point, an underscore or a letter, like in `5..` and `5.max`.
+    #[must_use]
+    pub const fn rust() -> Self {
+        Self::with_grammar(Grammar::Rust)
+    }
+
+    /// Constructs a new instance, describing a literal like C's `strtod`: with a sign, infinity, NaN,
+    /// empty integer or fractional parts (but not both), and hexadecimal literals, like `0x1.8p3`.
+    #[must_use]
+    pub const fn c() -> Self {
+        Self::with_grammar(Grammar::C)
+    }
+}
+
+/// Consumes digits in the given radix starting at the given index, passing each of them to the function.
+/// Returns the index after the digits, along with their amount.
+fn digits<F>(
+    input: &[u8],
+    mut index: usize,
+    radix: u32,
+    separators: bool,
+    mut f: F,
+) -> (usize, usize)
+where
+    F: FnMut(u32),
+{
+    let mut count: usize = 0;
+
+    while let Some(&byte) = input.get(index) {
+        if byte == b'_' && separators && count != 0 {
+            index += 1;
+        } else if let Some(digit) = char::from(byte).to_digit(radix) {
+            f(digit);
+
+            count += 1;
+            index += 1;
+        } else {
+            break;
+        }
+    }
+
+    (index, count)
+}
+
+/// Consumes an exponent's marker, sign and digits, returning the index after them along with the exponent.
+/// Nothing is consumed unless there is at least one digit.
+fn read_exponent(input: &[u8], index: usize, markers: [u8; 2], separators: bool) -> (usize, i64) {
+    // Exponents beyond this bound overflow or underflow any floating-point type anyway.
+    const BOUND: i64 = 1_000_000_000;
+
+    if !input
+        .get(index)
+        .is_some_and(|marker| markers.contains(marker))
+    {
+        return (index, 0);
+    }
+
+    let (start, negative): (usize, bool) = match input.get(index + 1) {
+        Some(b'-') => (index + 2, true),
+        Some(b'+') => (index + 2, false),
+        _ => (index + 1, false),
+    };
+
+    if !input.get(start).is_some_and(u8::is_ascii_digit) {
+        return (index, 0);
+    }
+
+    let mut value: i64 = 0;
+
+    let (end, _) = digits(input, start, 10, separators, |digit| {
+        value = (value * 10 + i64::from(digit)).min(BOUND);
+    });
+
+    (end, if negative { -value } else { value })
+}
+
+/// Significant decimal digits of a literal, scaled by a power of ten.
+struct Decimal {
+    digits: [u8; SIGNIFICANT_DIGITS + 1],
+    length: usize,
+    exponent: i64,
+    inexact: bool,
+}
+
+impl Decimal {
+    fn push(&mut self, digit: u32, fractional: bool) {
+        if self.length == 0 && digit == 0 {
+            // Leading zeros are insignificant.
+        } else if self.length < SIGNIFICANT_DIGITS {
+            self.digits[self.length] = b'0' + u8::try_from(digit).unwrap_or_default();
+            self.length += 1;
+        } else {
+            self.inexact |= digit != 0;
+
+            if !fractional {
+                self.exponent += 1;
+            }
+
+            return;
+        }
+
+        if fractional {
+            self.exponent -= 1;
+        }
+    }
+
+    /// Converts to the nearest value, by using the standard library's correctly rounded conversion.
+    fn value<N>(mut self, negative: bool, exponent: i64) -> Option<N>
+    where
+        N: Floating,
+    {
+        // Dropped non-zero digits are represented by a trailing one, which rounds the same way.
+        if self.inexact {
+            self.digits[self.length] = b'1';
+            self.length += 1;
+            self.exponent -= 1;
+        }
+
+        let mut buffer: [u8; SIGNIFICANT_DIGITS + 32] = [0; SIGNIFICANT_DIGITS + 32];
+        let mut length: usize = 0;
+
+        let mut write = |bytes: &[u8]| {
+            buffer[length..length + bytes.len()].copy_from_slice(bytes);
+            length += bytes.len();
+        };
+
+        if negative {
+            write(b"-");
+        }
+
+        if self.length == 0 {
+            write(b"0");
+        } else {
+            write(&self.digits[..self.length]);
+        }
+
+        let mut exponent: i64 = self.exponent.saturating_add(exponent);
+
+        write(if exponent < 0 { b"e-" } else { b"e" });
+
+        let mut reversed: [u8; 20] = [0; 20];
+        let mut count: usize = 0;
+
+        loop {
+            reversed[count] = b'0' + u8::try_from((exponent % 10).abs()).unwrap_or_default();
+            count += 1;
+            exponent /= 10;
+
+            if exponent == 0 {
+                break;
+            }
+        }
+
+        reversed[..count].reverse();
+        write(&reversed[..count]);
+
+        core::str::from_utf8(&buffer[..length])
+            .ok()
+            .and_then(|literal| literal.parse().ok())
+    }
+}
+
+/// Converts the value `mantissa * 2 ^ exponent` (slightly more when `inexact` is set) to the nearest value.
+fn binary<N>(mantissa: u64, exponent: i64, inexact: bool) -> N
+where
+    N: Floating,
+{
+    if mantissa == 0 {
+        return N::from_bits(0);
+    }
+
+    // Normalizes the mantissa, so its highest bit is set and the value is `1.xxx * 2 ^ power`.
+    let shift: u32 = mantissa.leading_zeros();
+    let mantissa: u128 = u128::from(mantissa << shift);
+    let power: i64 = exponent.saturating_sub(i64::from(shift)).saturating_add(63);
+
+    if power > N::MAX_EXPONENT {
+        return N::INFINITY;
+    }
+
+    // Subnormal values lose precision below the smallest normal exponent.
+    let dropped: i64 = i64::from(64 - N::PRECISION) + (N::MIN_EXPONENT - power).max(0);
+
+    let dropped: u32 = if let Ok(dropped) = u32::try_from(dropped.min(66)) {
+        dropped
+    } else {
+        return N::from_bits(0);
+    };
+
+    let kept: u128 = mantissa >> dropped;
+    let remainder: u128 = mantissa & ((1 << dropped) - 1);
+    let half: u128 = 1 << (dropped - 1);
+
+    let round_up: bool = remainder > half || (remainder == half && (inexact || kept & 1 == 1));
+
+    let kept: u128 = kept + u128::from(round_up);
+
+    let bits: u128 = if power < N::MIN_EXPONENT {
+        // A subnormal value that rounds up to the smallest normal one carries into the exponent's bits.
+        kept
+    } else {
+        let biased: u128 = u128::try_from(power - N::MIN_EXPONENT).unwrap_or_default();
+
+        // A mantissa that rounds up to the next power of two carries into the exponent's bits as well.
+        (biased << (N::PRECISION - 1)) + kept
+    };
+
+    if bits >> (N::PRECISION - 1)
+        > u128::try_from(N::MAX_EXPONENT - N::MIN_EXPONENT + 1).unwrap_or_default()
+    {
+        N::INFINITY
+    } else {
+        N::from_bits(u64::try_from(bits).unwrap_or_default())
+    }
+}
+
+impl<N> Float<N>
+where
+    N: Floating,
+{
+    fn read_special(input: &[u8]) -> Option<(usize, N)> {
+        // The word must end there, so that identifiers like `information` aren't taken apart.
+        let starts_with = |word: &[u8]| {
+            input
+                .get(..word.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(word))
+                && input
+                    .get(word.len())
+                    .map_or(true, |&next| !next.is_ascii_alphanumeric() && next != b'_')
+        };
+
+        if starts_with(b"infinity") {
+            Some((8, N::INFINITY))
+        } else if starts_with(b"inf") {
+            Some((3, N::INFINITY))
+        } else if starts_with(b"nan") {
+            Some((3, N::NAN))
+        } else {
+            None
+        }
+    }
+
+    fn read_hexadecimal(input: &[u8], start: usize) -> Result<(usize, N), NumberError> {
+        let mut mantissa: u64 = 0;
+        let mut exponent: i64 = 0;
+        let mut inexact: bool = false;
+
+        let mut push = |digit: u32, fractional: bool| {
+            if mantissa >> 60 == 0 {
+                mantissa = mantissa << 4 | u64::from(digit);
+
+                if fractional {
+                    exponent -= 4;
+                }
+            } else {
+                inexact |= digit != 0;
+
+                if !fractional {
+                    exponent += 4;
+                }
+            }
+        };
+
+        let (mut index, integer) = digits(input, start, 16, false, |digit| push(digit, false));
+        let mut fraction: usize = 0;
+
+        if input.get(index) == Some(&b'.') {
+            let (end, count) = digits(input, index + 1, 16, false, |digit| push(digit, true));
+
+            if integer != 0 || count != 0 {
+                index = end;
+                fraction = count;
+            }
+        }
+
+        if integer == 0 && fraction == 0 {
+            return Err(NumberError::Digit(start, 16));
+        }
+
+        let (index, power) = read_exponent(input, index, [b'p', b'P'], false);
+
+        Ok((
+            index,
+            binary(mantissa, exponent.saturating_add(power), inexact),
+        ))
+    }
+
+    fn read_decimal(
+        self,
+        input: &[u8],
+        start: usize,
+        negative: bool,
+    ) -> Result<(usize, N), NumberError> {
+        let grammar: Grammar = self.grammar;
+
+        let mut decimal: Decimal = Decimal {
+            digits: [0; SIGNIFICANT_DIGITS + 1],
+            length: 0,
+            exponent: 0,
+            inexact: false,
+        };
+
+        let (mut index, integer) = if !grammar.leading_zeros() && input.get(start) == Some(&b'0') {
+            (start + 1, 1)
+        } else {
+            digits(input, start, 10, grammar.separators(), |digit| {
+                decimal.push(digit, false);
+            })
+        };
+
+        if integer == 0 && !grammar.empty_integer() {
+            return Err(NumberError::Digit(start, 10));
+        }
+
+        let mut fraction: usize = 0;
+
+        if input.get(index) == Some(&b'.') {
+            let next: Option<u8> = input.get(index + 1).copied();
+
+            let accepted: bool = match next {
+                Some(next) if next.is_ascii_digit() => true,
+                Some(next)
+                    if grammar.guarded_point()
+                        && (next == b'.' || next == b'_' || next.is_ascii_alphabetic()) =>
+                {
+                    false
+                }
+                _ => grammar.empty_fraction() && integer != 0,
+            };
+
+            if accepted {
+                let (end, count) = digits(input, index + 1, 10, grammar.separators(), |digit| {
+                    decimal.push(digit, true);
+                });
+
+                index = end;
+                fraction = count;
+            }
+        }
+
+        if integer == 0 && fraction == 0 {
+            return Err(NumberError::Digit(start, 10));
+        }
+
+        let (index, power) = read_exponent(input, index, [b'e', b'E'], grammar.separators());
+
+        decimal
+            .value(negative, power)
+            .map(|value| (index, value))
+            .ok_or(NumberError::Digit(start, 10))
+    }
+}
+
+impl<N> Format for Float<N>
+where
+    N: Floating,
+{
+    type Value = N;
+
+    fn read(self, input: &[u8]) -> Result<(usize, N), NumberError> {
+        let (start, negative): (usize, bool) = match input.first() {
+            Some(b'-') => (1, true),
+            Some(b'+') if self.grammar.plus() => (1, false),
+            _ => (0, false),
+        };
+
+        let sign = |value: N| if negative { -value } else { value };
+
+        let rest: &[u8] = input.get(start..).unwrap_or_default();
+
+        if self.grammar.special() {
+            if let Some((length, value)) = Self::read_special(rest) {
+                return Ok((start + length, sign(value)));
+            }
+        }
+
+        if let (true, [b'0', b'x' | b'X', ..]) = (self.grammar.hexadecimal(), rest) {
+            // Without any hexadecimal digits, only the zero before the `x` is a number.
+            if let Ok((index, value)) = Self::read_hexadecimal(input, start + 2) {
+                return Ok((index, sign(value)));
+            }
+        }
+
+        self.read_decimal(input, start, negative)
+    }
+}
+
+/// Parser that matches a floating-point literal of the described format.
+impl<N, I, R> Parser<I, R, Float<N>> for Float<N>
+where
+    Self: Copy,
+    I: MatchNumber<Self, R>,
+{
+    fn parse(&mut self, input: I) -> R {
+        input.match_number(*self)
+    }
+}
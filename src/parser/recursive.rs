@@ -0,0 +1,157 @@
+use core::cell::Cell;
+
+use crate::{
+    input::Fragment,
+    parser::Parser,
+    result::{Expected, Found, MatchFailed},
+    traits::MatchFail,
+};
+
+/// Default maximal depth of nested invocations of a recursive rule.
+pub const DEFAULT_RECURSION_LIMIT: usize = 128;
+
+fn recursion_limit<I, R>(input: I, limit: usize) -> R
+where
+    I: Fragment,
+    R: MatchFail,
+{
+    R::failed_with(MatchFailed::new(
+        input.length(),
+        Expected::Unknown,
+        Found::RecursionLimit(limit),
+    ))
+}
+
+/// Recursive rule, built as a fixpoint of the passed function.
+/// ## Notes
+/// The function receives a reference to the rule itself along with the input.
+/// Once the nested invocations reach the recursion limit, matching fails instead of overflowing the stack.
+/// Invocations that end up not matching count towards the limit as well.
+/// The failure is unrecoverable, so it is forwarded by `optional` and alternatives.
+/// It is located at the input of the invocation that exceeded the limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Recursive<F> {
+    rule: F,
+    limit: usize,
+}
+
+impl<F> Recursive<F> {
+    /// Constructs a new instance with the default recursion limit.
+    pub const fn new(rule: F) -> Self {
+        Self::with_limit(rule, DEFAULT_RECURSION_LIMIT)
+    }
+
+    /// Constructs a new instance with the passed recursion limit.
+    pub const fn with_limit(rule: F, limit: usize) -> Self {
+        Self { rule, limit }
+    }
+
+    fn call<I, R>(&self, input: I, depth: usize) -> R
+    where
+        I: Fragment,
+        F: Fn(&mut dyn FnMut(I) -> R, I) -> R,
+        R: MatchFail,
+    {
+        if depth < self.limit {
+            (self.rule)(&mut |input: I| self.call(input, depth + 1), input)
+        } else {
+            recursion_limit(input, self.limit)
+        }
+    }
+}
+
+impl<I, R, F> Parser<I, R, Recursive<()>> for Recursive<F>
+where
+    I: Fragment,
+    F: Fn(&mut dyn FnMut(I) -> R, I) -> R,
+    R: MatchFail,
+{
+    fn parse(&mut self, input: I) -> R {
+        self.call(input, 0)
+    }
+}
+
+/// Forward-declared rule, which is defined after being declared.
+/// This allows rules to refer to themselves or to each other.
+/// ## Notes
+/// Matching an undefined rule fails.
+/// Once the nested invocations reach the recursion limit, matching fails instead of overflowing the stack.
+/// See [`Recursive`] for details.
+///
+/// [`Recursive`]: struct.Recursive.html
+pub struct Forward<'a, I, R> {
+    rule: Cell<Option<&'a dyn Fn(I) -> R>>,
+    depth: Cell<usize>,
+    limit: usize,
+}
+
+impl<'a, I, R> Forward<'a, I, R> {
+    /// Constructs a new undefined instance with the default recursion limit.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_limit(DEFAULT_RECURSION_LIMIT)
+    }
+
+    /// Constructs a new undefined instance with the passed recursion limit.
+    #[must_use]
+    pub const fn with_limit(limit: usize) -> Self {
+        Self {
+            rule: Cell::new(None),
+            depth: Cell::new(0),
+            limit,
+        }
+    }
+
+    /// Defines the rule, replacing the previous definition.
+    pub fn define(&self, rule: &'a dyn Fn(I) -> R) {
+        self.rule.set(Some(rule));
+    }
+
+    /// Returns boolean indicating whether the rule is defined.
+    pub fn is_defined(&self) -> bool {
+        self.rule.get().is_some()
+    }
+
+    /// Matches the rule against the passed input.
+    pub fn parse(&self, input: I) -> R
+    where
+        I: Fragment,
+        R: MatchFail,
+    {
+        let rule: &dyn Fn(I) -> R = if let Some(rule) = self.rule.get() {
+            rule
+        } else {
+            return R::failed_with(MatchFailed::unknown().with_expected(Expected::Undefined));
+        };
+
+        let depth: usize = self.depth.get();
+
+        if depth < self.limit {
+            self.depth.set(depth + 1);
+
+            let result: R = rule(input);
+
+            self.depth.set(depth);
+
+            result
+        } else {
+            recursion_limit(input, self.limit)
+        }
+    }
+}
+
+impl<I, R> Default for Forward<'_, I, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, I, R> Parser<I, R, Forward<'a, (), ()>> for &Forward<'a, I, R>
+where
+    I: Fragment,
+    R: MatchFail,
+{
+    fn parse(&mut self, input: I) -> R {
+        Forward::parse(self, input)
+    }
+}
@@ -0,0 +1,515 @@
+use core::convert::TryFrom;
+
+use crate::{parser::Parser, traits::MatchString};
+
+mod private {
+    use crate::result::{Expected, Found, MatchFailed, Snippet};
+
+    /// Reason for which matching a string literal failed.
+    pub enum StringError {
+        /// The given quote was expected at the given offset.
+        Quote(usize, u8),
+        /// A known escape sequence was expected at the given offset.
+        Escape(usize),
+        /// The given control character at the given offset was expected to be escaped.
+        Control(usize, u8),
+        /// A hexadecimal digit was expected at the given offset.
+        Digit(usize),
+        /// The given text was expected at the given offset.
+        Text(usize, &'static str),
+    }
+
+    impl StringError {
+        /// Converts into a failure of matching the passed amount of elements,
+        /// describing the element found at the offset by using the passed function.
+        pub fn into_failure<F>(self, length: usize, found: F) -> MatchFailed
+        where
+            F: FnOnce(usize) -> Found,
+        {
+            let (offset, expected, control): (usize, Expected, Option<u8>) = match self {
+                Self::Quote(offset, quote) => (
+                    offset,
+                    Expected::Text(Snippet::new(
+                        core::str::from_utf8(&[quote]).unwrap_or_default(),
+                    )),
+                    None,
+                ),
+                Self::Escape(offset) => (offset, Expected::Escape, None),
+                Self::Control(offset, byte) => (offset, Expected::Escape, Some(byte)),
+                Self::Digit(offset) => (offset, Expected::Digit(16), None),
+                Self::Text(offset, text) => (offset, Expected::Text(Snippet::new(text)), None),
+            };
+
+            MatchFailed::new(
+                length.saturating_sub(offset),
+                expected,
+                match control {
+                    Some(byte) => Found::Control(byte),
+                    None if offset < length => found(offset),
+                    None => Found::EndOfInput,
+                },
+            )
+        }
+    }
+
+    /// Describes the contents of literals within `&str` and `&[u8]`.
+    pub trait Text {
+        /// Whether the contents consist of characters, rather than arbitrary bytes.
+        const CHARACTERS: bool;
+
+        /// Decoded contents, owning their data.
+        #[cfg(feature = "std")]
+        type Buffer: core::borrow::Borrow<Self>;
+
+        fn bytes(&self) -> &[u8];
+
+        /// Returns the contents of the literal with the passed length, between the quotes.
+        fn contents(&self, length: usize) -> &Self;
+
+        #[cfg(feature = "std")]
+        fn buffer(bytes: Vec<u8>) -> Self::Buffer;
+    }
+
+    impl Text for str {
+        const CHARACTERS: bool = true;
+
+        #[cfg(feature = "std")]
+        type Buffer = String;
+
+        fn bytes(&self) -> &[u8] {
+            self.as_bytes()
+        }
+
+        fn contents(&self, length: usize) -> &Self {
+            self.get(1..length - 1).unwrap_or_default()
+        }
+
+        #[cfg(feature = "std")]
+        fn buffer(bytes: Vec<u8>) -> String {
+            // Escape sequences produce whole characters only, so the bytes are always valid UTF-8.
+            String::from_utf8(bytes).unwrap_or_default()
+        }
+    }
+
+    impl Text for [u8] {
+        const CHARACTERS: bool = false;
+
+        #[cfg(feature = "std")]
+        type Buffer = Vec<u8>;
+
+        fn bytes(&self) -> &[u8] {
+            self
+        }
+
+        fn contents(&self, length: usize) -> &Self {
+            self.get(1..length - 1).unwrap_or_default()
+        }
+
+        #[cfg(feature = "std")]
+        fn buffer(bytes: Vec<u8>) -> Vec<u8> {
+            bytes
+        }
+    }
+}
+
+use private::Text;
+
+pub(crate) use private::StringError;
+
+/// Contents of a string literal, between the quotes, as produced by [`MatchString::match_string`].
+/// ## Notes
+/// Decoding the escape sequences requires the `std` feature, as the decoded contents have to be owned.
+/// With it, the contents of literals with escape sequences are [`Owned`]. Without it, they are [`Escaped`],
+/// left as they appear in the input.
+/// The enumeration is non-exhaustive, so matching on it doesn't depend on the enabled features.
+///
+/// [`MatchString::match_string`]: ../traits/trait.MatchString.html#tymethod.match_string
+/// [`Owned`]: #variant.Owned
+/// [`Escaped`]: #variant.Escaped
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Contents<'a, T>
+where
+    T: Text + ?Sized,
+{
+    /// Contents without escape sequences, borrowed from the input.
+    Borrowed(&'a T),
+    /// Contents of a literal with escape sequences, left undecoded.
+    /// # Notes
+    /// This variant is produced only without the `std` feature, as the contents are decoded into
+    /// [`Owned`](#variant.Owned) with it.
+    Escaped(&'a T),
+    /// Decoded contents of a literal with escape sequences.
+    /// # Notes
+    /// This variant is available only with the `std` feature.
+    #[cfg(feature = "std")]
+    Owned(T::Buffer),
+}
+
+impl<T> Contents<'_, T>
+where
+    T: Text + ?Sized,
+{
+    /// Returns the decoded contents, or `None` when escape sequences were left undecoded.
+    #[must_use]
+    pub fn decoded(&self) -> Option<&T> {
+        match self {
+            Self::Borrowed(contents) => Some(contents),
+            Self::Escaped(_) => None,
+            #[cfg(feature = "std")]
+            Self::Owned(contents) => Some(core::borrow::Borrow::borrow(contents)),
+        }
+    }
+}
+
+/// Describes which form of Unicode escape sequences is accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Unicode {
+    None,
+    /// `\u{1F600}`, with one to six hexadecimal digits.
+    Braced,
+    /// `\u00E9`, with exactly four hexadecimal digits, combining surrogate pairs.
+    Fixed,
+}
+
+/// Format of a quoted string literal, matched by [`MatchString::match_string`].
+/// ## Notes
+/// An escape sequence consists of the escape character followed by one of:
+/// * the escape character or the quote, producing themselves;
+/// * a byte from the escape table, producing the byte associated with it;
+/// * `x` and two hexadecimal digits, when enabled;
+/// * `u` and a Unicode code point, in the enabled form.
+///
+/// In case of `&str`, bytes produced by the escape table are decoded as the characters with the same
+/// code points, while `\x` sequences are limited to ASCII, so that the contents remain valid UTF-8.
+///
+/// [`MatchString::match_string`]: ../traits/trait.MatchString.html#tymethod.match_string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quoted<'a> {
+    quote: u8,
+    escape: u8,
+    table: &'a [(u8, u8)],
+    hexadecimal: bool,
+    unicode: Unicode,
+    forbid_control: bool,
+}
+
+impl Quoted<'_> {
+    /// Constructs a new instance, describing a literal enclosed in the passed quote.
+    /// Only the escape character (by default `\`) and the quote can be escaped.
+    /// # Panics
+    /// Panics when the quote isn't an ASCII character.
+    #[must_use]
+    pub const fn new(quote: u8) -> Self {
+        assert!(quote.is_ascii(), "quote must be an ASCII character");
+
+        Self {
+            quote,
+            escape: b'\\',
+            table: &[],
+            hexadecimal: false,
+            unicode: Unicode::None,
+            forbid_control: false,
+        }
+    }
+
+    /// Constructs a new instance, describing a string as defined by JSON.
+    /// Escapes `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t` and `\uXXXX` are accepted,
+    /// while control characters (below U+0020) have to be escaped.
+    #[must_use]
+    pub const fn json() -> Quoted<'static> {
+        Quoted::new(b'"')
+            .table(&[
+                (b'/', b'/'),
+                (b'b', 0x08),
+                (b'f', 0x0C),
+                (b'n', b'\n'),
+                (b'r', b'\r'),
+                (b't', b'\t'),
+            ])
+            .fixed_unicode()
+            .forbid_control()
+    }
+
+    /// Constructs a new instance, describing a string as defined by Rust.
+    /// Escapes `\"`, `\'`, `\\`, `\0`, `\n`, `\r`, `\t`, `\xNN` and `\u{N..}` are accepted.
+    #[must_use]
+    pub const fn rust() -> Quoted<'static> {
+        Quoted::new(b'"')
+            .table(&[
+                (b'\'', b'\''),
+                (b'0', 0),
+                (b'n', b'\n'),
+                (b'r', b'\r'),
+                (b't', b'\t'),
+            ])
+            .hexadecimal()
+            .braced_unicode()
+    }
+
+    /// Returns an instance that uses the passed escape character.
+    /// # Panics
+    /// Panics when the escape character isn't an ASCII character.
+    #[must_use]
+    pub const fn escape(mut self, escape: u8) -> Self {
+        assert!(escape.is_ascii(), "escape must be an ASCII character");
+
+        self.escape = escape;
+
+        self
+    }
+
+    /// Returns an instance that uses the passed escape table,
+    /// which associates the bytes following the escape character with the bytes they produce.
+    #[must_use]
+    pub const fn table(self, table: &[(u8, u8)]) -> Quoted<'_> {
+        Quoted {
+            quote: self.quote,
+            escape: self.escape,
+            table,
+            hexadecimal: self.hexadecimal,
+            unicode: self.unicode,
+            forbid_control: self.forbid_control,
+        }
+    }
+
+    /// Returns an instance that accepts `\xNN` escapes, with exactly two hexadecimal digits.
+    #[must_use]
+    pub const fn hexadecimal(mut self) -> Self {
+        self.hexadecimal = true;
+
+        self
+    }
+
+    /// Returns an instance that accepts `\u{N..}` escapes, with one to six hexadecimal digits.
+    #[must_use]
+    pub const fn braced_unicode(mut self) -> Self {
+        self.unicode = Unicode::Braced;
+
+        self
+    }
+
+    /// Returns an instance that accepts `\uXXXX` escapes, with exactly four hexadecimal digits.
+    /// Surrogates have to form pairs of consecutive escapes, which are combined into a single character.
+    #[must_use]
+    pub const fn fixed_unicode(mut self) -> Self {
+        self.unicode = Unicode::Fixed;
+
+        self
+    }
+
+    /// Returns an instance that rejects control characters (below U+0020) appearing unescaped in the literal.
+    #[must_use]
+    pub const fn forbid_control(mut self) -> Self {
+        self.forbid_control = true;
+
+        self
+    }
+
+    /// Reads the literal at the start of the input, returning its length along with its contents.
+    pub(crate) fn read<T>(self, input: &T) -> Result<(usize, Contents<'_, T>), StringError>
+    where
+        T: Text + ?Sized,
+    {
+        #[cfg(feature = "std")]
+        {
+            let mut buffer: Vec<u8> = Vec::new();
+
+            let (length, escaped): (usize, bool) =
+                self.decode(input.bytes(), T::CHARACTERS, |bytes| {
+                    buffer.extend_from_slice(bytes);
+                })?;
+
+            if escaped {
+                Ok((length, Contents::Owned(T::buffer(buffer))))
+            } else {
+                Ok((length, Contents::Borrowed(input.contents(length))))
+            }
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            let (length, escaped): (usize, bool) =
+                self.decode(input.bytes(), T::CHARACTERS, |_| ())?;
+
+            if escaped {
+                Ok((length, Contents::Escaped(input.contents(length))))
+            } else {
+                Ok((length, Contents::Borrowed(input.contents(length))))
+            }
+        }
+    }
+
+    /// Reads the literal at the start of the input, passing the decoded contents to the sink piece by piece.
+    /// Returns the length of the literal, along with boolean indicating whether it had escape sequences.
+    /// When it had none, the sink isn't called at all.
+    fn decode<S>(self, input: &[u8], text: bool, mut sink: S) -> Result<(usize, bool), StringError>
+    where
+        S: FnMut(&[u8]),
+    {
+        if input.first() != Some(&self.quote) {
+            return Err(StringError::Quote(0, self.quote));
+        }
+
+        let mut index: usize = 1;
+        let mut start: usize = 1;
+        let mut escaped: bool = false;
+
+        loop {
+            match input.get(index) {
+                None => return Err(StringError::Quote(index, self.quote)),
+                Some(&byte) if byte == self.quote => {
+                    if escaped {
+                        sink(&input[start..index]);
+                    }
+
+                    return Ok((index + 1, escaped));
+                }
+                Some(&byte) if byte == self.escape => {
+                    sink(&input[start..index]);
+
+                    index = self.read_escape(input, index + 1, text, &mut sink)?;
+                    start = index;
+                    escaped = true;
+                }
+                Some(&byte) if byte < 0x20 && self.forbid_control => {
+                    return Err(StringError::Control(index, byte))
+                }
+                Some(_) => index += 1,
+            }
+        }
+    }
+
+    /// Reads the escape sequence following the escape character, returning the index right after it.
+    fn read_escape<S>(
+        self,
+        input: &[u8],
+        index: usize,
+        text: bool,
+        sink: &mut S,
+    ) -> Result<usize, StringError>
+    where
+        S: FnMut(&[u8]),
+    {
+        let byte: u8 = *input.get(index).ok_or(StringError::Escape(index))?;
+
+        if byte == self.escape || byte == self.quote {
+            sink(&[byte]);
+
+            return Ok(index + 1);
+        }
+
+        if let Some(&(_, produced)) = self.table.iter().find(|&&(escape, _)| escape == byte) {
+            if text {
+                sink(char::from(produced).encode_utf8(&mut [0; 4]).as_bytes());
+            } else {
+                sink(&[produced]);
+            }
+
+            return Ok(index + 1);
+        }
+
+        match (byte, self.unicode) {
+            (b'x', _) if self.hexadecimal => {
+                let value: u32 = hexadecimal(input, index + 1, 2)?;
+
+                match u8::try_from(value) {
+                    Ok(value) if !text || value.is_ascii() => sink(&[value]),
+                    _ => return Err(StringError::Escape(index)),
+                }
+
+                Ok(index + 3)
+            }
+            (b'u', Unicode::Braced) => {
+                if input.get(index + 1) != Some(&b'{') {
+                    return Err(StringError::Text(index + 1, "{"));
+                }
+
+                let mut end: usize = index + 2;
+
+                while input.get(end).is_some_and(u8::is_ascii_hexdigit) {
+                    end += 1;
+                }
+
+                if end == index + 2 {
+                    return Err(StringError::Digit(end));
+                }
+
+                if end - (index + 2) > 6 {
+                    return Err(StringError::Text(index + 8, "}"));
+                }
+
+                if input.get(end) != Some(&b'}') {
+                    return Err(StringError::Text(end, "}"));
+                }
+
+                let value: u32 = hexadecimal(input, index + 2, end - (index + 2))?;
+
+                let c: char = char::from_u32(value).ok_or(StringError::Escape(index))?;
+
+                sink(c.encode_utf8(&mut [0; 4]).as_bytes());
+
+                Ok(end + 1)
+            }
+            (b'u', Unicode::Fixed) => {
+                let high: u32 = hexadecimal(input, index + 1, 4)?;
+
+                let value: u32 = match high {
+                    0xD800..=0xDBFF => {
+                        // A high surrogate has to be followed by an escaped low one.
+                        let next: usize = index + 5;
+
+                        if input.get(next) != Some(&self.escape)
+                            || input.get(next + 1) != Some(&b'u')
+                        {
+                            return Err(StringError::Escape(next));
+                        }
+
+                        let low: u32 = hexadecimal(input, next + 2, 4)?;
+
+                        if !(0xDC00..=0xDFFF).contains(&low) {
+                            return Err(StringError::Escape(next + 1));
+                        }
+
+                        let c: char =
+                            char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+                                .ok_or(StringError::Escape(index))?;
+
+                        sink(c.encode_utf8(&mut [0; 4]).as_bytes());
+
+                        return Ok(next + 6);
+                    }
+                    value => value,
+                };
+
+                let c: char = char::from_u32(value).ok_or(StringError::Escape(index))?;
+
+                sink(c.encode_utf8(&mut [0; 4]).as_bytes());
+
+                Ok(index + 5)
+            }
+            _ => Err(StringError::Escape(index)),
+        }
+    }
+}
+
+/// Reads exactly the passed amount of hexadecimal digits, starting at the passed index.
+fn hexadecimal(input: &[u8], index: usize, count: usize) -> Result<u32, StringError> {
+    (index..index + count).try_fold(0, |value: u32, index| {
+        input
+            .get(index)
+            .and_then(|&byte| char::from(byte).to_digit(16))
+            .map(|digit| value << 4 | digit)
+            .ok_or(StringError::Digit(index))
+    })
+}
+
+/// Parser that matches a string literal of the described format.
+impl<'a, I, R> Parser<I, R, Quoted<'a>> for Quoted<'a>
+where
+    I: MatchString<Self, R>,
+{
+    fn parse(&mut self, input: I) -> R {
+        input.match_string(*self)
+    }
+}
@@ -0,0 +1,211 @@
+use core::{convert::TryFrom, marker::PhantomData};
+
+use crate::{
+    parser::Parser,
+    result::{Expected, Found, MatchFailed},
+    traits::MatchBinary,
+};
+
+mod private {
+    use core::convert::TryFrom;
+
+    pub trait Variable: Copy + TryFrom<u128> + TryFrom<i128> {
+        const BITS: u32;
+    }
+
+    macro_rules! impl_variable {
+        ($($t: ty),+ $(,)?) => {
+            $(
+                impl Variable for $t {
+                    const BITS: u32 = <$t>::BITS;
+                }
+            )+
+        };
+    }
+
+    impl_variable!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+}
+
+pub(crate) use private::Variable;
+
+/// Describes how the value is stored in the groups of seven bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Encoding {
+    Unsigned,
+    Signed,
+    Zigzag,
+}
+
+/// Format of a variable-length integer, matched by [`MatchBinary::match_binary`] as a value of type `N`.
+/// ## Notes
+/// Each byte holds seven bits of the value, starting from the least significant ones,
+/// while its most significant bit tells whether more bytes follow.
+/// Values are limited to the width of `N`, so encodings that are longer than needed for it or that
+/// don't fit into it fail to match, with the failure positioned at their start.
+/// Encodings with redundant trailing bytes (overlong ones) fail as well, unless allowed.
+///
+/// [`MatchBinary::match_binary`]: ../traits/trait.MatchBinary.html#tymethod.match_binary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Varint<N> {
+    encoding: Encoding,
+    overlong: bool,
+    number: PhantomData<N>,
+}
+
+impl<N> Varint<N> {
+    const fn with_encoding(encoding: Encoding) -> Self {
+        Self {
+            encoding,
+            overlong: false,
+            number: PhantomData,
+        }
+    }
+
+    /// Constructs a new instance, describing an unsigned LEB128 integer,
+    /// which is also the "varint" of Protocol Buffers.
+    #[must_use]
+    pub const fn leb128() -> Self {
+        Self::with_encoding(Encoding::Unsigned)
+    }
+
+    /// Constructs a new instance, describing a signed LEB128 integer, in two's complement.
+    #[must_use]
+    pub const fn signed_leb128() -> Self {
+        Self::with_encoding(Encoding::Signed)
+    }
+
+    /// Constructs a new instance, describing a zigzag-encoded integer, as the "sint" types of Protocol Buffers.
+    /// Zigzag encoding maps signed values to unsigned ones (`0, -1, 1, -2, ...` to `0, 1, 2, 3, ...`),
+    /// which are then stored as unsigned LEB128.
+    #[must_use]
+    pub const fn zigzag() -> Self {
+        Self::with_encoding(Encoding::Zigzag)
+    }
+
+    /// Returns an instance that accepts overlong encodings, like `0x80 0x00` for zero,
+    /// as long as they are within the width of `N`.
+    #[must_use]
+    pub const fn allow_overlong(mut self) -> Self {
+        self.overlong = true;
+
+        self
+    }
+}
+
+impl<N> Varint<N>
+where
+    N: Variable,
+{
+    /// Reads the integer at the start of the input, returning its length along with its value.
+    pub(crate) fn read(self, input: &[u8]) -> Result<(usize, N), MatchFailed> {
+        let failure = |found: Found| MatchFailed::new(input.len(), Expected::Number, found);
+
+        // The amount of bytes needed for the widest values of `N`.
+        let limit: u32 = N::BITS.div_ceil(7);
+
+        let mut raw: u128 = 0;
+        let mut count: u32 = 0;
+
+        let last: u8 = loop {
+            let byte: u8 = if let Some(&byte) = usize::try_from(count)
+                .ok()
+                .and_then(|index| input.get(index))
+            {
+                byte
+            } else {
+                let length: usize = usize::try_from(count + 1).unwrap_or(usize::MAX);
+
+                return Err(MatchFailed::new(
+                    input.len(),
+                    Expected::Elements(length),
+                    Found::EndOfInput,
+                ));
+            };
+
+            let shift: u32 = 7 * count;
+            let payload: u8 = byte & 0x7F;
+
+            count += 1;
+
+            if count == limit {
+                // Only the lowest bits of the last byte belong to the value, the rest must be zeros,
+                // or copies of the sign bit in case of signed encoding.
+                let bits: u32 = N::BITS - shift;
+
+                let fits: bool = match self.encoding {
+                    Encoding::Signed => {
+                        let extension: u8 = payload >> (bits - 1);
+
+                        extension == 0 || extension == 0x7F >> (bits - 1)
+                    }
+                    Encoding::Unsigned | Encoding::Zigzag => payload >> bits == 0,
+                };
+
+                if !fits || byte & 0x80 != 0 {
+                    return Err(failure(Found::Overflow));
+                }
+            }
+
+            raw |= u128::from(payload) << shift;
+
+            if byte & 0x80 == 0 {
+                break byte;
+            }
+        };
+
+        if !self.overlong && count > 1 {
+            let previous: u8 = input[usize::try_from(count - 2).unwrap_or_default()];
+
+            let redundant: bool = match self.encoding {
+                Encoding::Signed => {
+                    (last == 0x00 && previous & 0x40 == 0) || (last == 0x7F && previous & 0x40 != 0)
+                }
+                Encoding::Unsigned | Encoding::Zigzag => last == 0x00,
+            };
+
+            if redundant {
+                return Err(failure(Found::Overlong));
+            }
+        }
+
+        let value: Option<N> = match self.encoding {
+            Encoding::Unsigned => N::try_from(raw).ok(),
+            Encoding::Signed => {
+                let bits: u32 = 7 * count;
+
+                let extended: u128 = if bits < 128 && last & 0x40 != 0 {
+                    raw | u128::MAX << bits
+                } else {
+                    raw
+                };
+
+                N::try_from(i128::from_ne_bytes(extended.to_ne_bytes())).ok()
+            }
+            Encoding::Zigzag => {
+                let magnitude: i128 = i128::from_ne_bytes((raw >> 1).to_ne_bytes());
+
+                N::try_from(if raw & 1 == 0 {
+                    magnitude
+                } else {
+                    -magnitude - 1
+                })
+                .ok()
+            }
+        };
+
+        value
+            .map(|value| (usize::try_from(count).unwrap_or_default(), value))
+            .ok_or_else(|| failure(Found::Overflow))
+    }
+}
+
+/// Parser that matches a variable-length integer of the described format.
+impl<N, I, R> Parser<I, R, Varint<N>> for Varint<N>
+where
+    Self: Copy,
+    I: MatchBinary<Self, R>,
+{
+    fn parse(&mut self, input: I) -> R {
+        input.match_binary(*self)
+    }
+}
@@ -0,0 +1,231 @@
+//! This module holds the [`Predicate`] trait, along with ready-made and composable predicates.
+//!
+//! Predicates are accepted by [`MatchWith`] and [`MatchWithInRange`] in place of closures,
+//! for both `&str` (as `Predicate<char>`) and `&[E]` (as `Predicate<E>`).
+//! ## Sets
+//! * Ranges, like `'a'..='z'` and `b'0'..b'8'`, hold the elements within them.
+//! * Arrays and slices, like `['+', '-']` and `b"+-"`, hold the listed elements.
+//! * String slices, like `"+-"`, hold the listed characters.
+//! * [`ByteSet`] holds bytes in a bitmap, making it the fastest option for `u8`.
+//!
+//! ## Composition
+//! Predicates are composed by using [`Union`], [`Intersection`] and [`Complement`],
+//! while closures are made composable by wrapping them in [`Satisfy`].
+//!
+//! [`Predicate`]: trait.Predicate.html
+//! [`ByteSet`]: struct.ByteSet.html
+//! [`Union`]: struct.Union.html
+//! [`Intersection`]: struct.Intersection.html
+//! [`Complement`]: struct.Complement.html
+//! [`Satisfy`]: struct.Satisfy.html
+//! [`MatchWith`]: ../traits/trait.MatchWith.html
+//! [`MatchWithInRange`]: ../traits/trait.MatchWithInRange.html
+
+use core::{
+    marker::PhantomData,
+    ops::{Range, RangeInclusive},
+};
+
+/// Provides interface for testing single elements.
+pub trait Predicate<E> {
+    /// Returns boolean indicating whether the element satisfies the predicate.
+    fn test(&self, element: &E) -> bool;
+}
+
+/// Helper type used to tell apart implementations of [`MatchWith`] and [`MatchWithInRange`] for predicates.
+///
+/// [`MatchWith`]: ../traits/trait.MatchWith.html
+/// [`MatchWithInRange`]: ../traits/trait.MatchWithInRange.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByPredicate<E>(PhantomData<E>);
+
+impl<E, P> Predicate<E> for &P
+where
+    P: Predicate<E> + ?Sized,
+{
+    fn test(&self, element: &E) -> bool {
+        (**self).test(element)
+    }
+}
+
+impl<E> Predicate<E> for Range<E>
+where
+    E: PartialOrd,
+{
+    fn test(&self, element: &E) -> bool {
+        self.contains(element)
+    }
+}
+
+impl<E> Predicate<E> for RangeInclusive<E>
+where
+    E: PartialOrd,
+{
+    fn test(&self, element: &E) -> bool {
+        self.contains(element)
+    }
+}
+
+impl<E> Predicate<E> for [E]
+where
+    E: PartialEq,
+{
+    fn test(&self, element: &E) -> bool {
+        self.contains(element)
+    }
+}
+
+impl<E, const N: usize> Predicate<E> for [E; N]
+where
+    E: PartialEq,
+{
+    fn test(&self, element: &E) -> bool {
+        self.contains(element)
+    }
+}
+
+impl Predicate<char> for str {
+    fn test(&self, element: &char) -> bool {
+        self.contains(*element)
+    }
+}
+
+/// Predicate satisfied by elements satisfying the wrapped function, which allows closures to be composed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Satisfy<F>(pub F);
+
+impl<E, F> Predicate<E> for Satisfy<F>
+where
+    F: Fn(&E) -> bool,
+{
+    fn test(&self, element: &E) -> bool {
+        (self.0)(element)
+    }
+}
+
+/// Predicate satisfied by elements satisfying either of both predicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Union<A, B>(pub A, pub B);
+
+impl<E, A, B> Predicate<E> for Union<A, B>
+where
+    A: Predicate<E>,
+    B: Predicate<E>,
+{
+    fn test(&self, element: &E) -> bool {
+        self.0.test(element) || self.1.test(element)
+    }
+}
+
+/// Predicate satisfied by elements satisfying both predicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Intersection<A, B>(pub A, pub B);
+
+impl<E, A, B> Predicate<E> for Intersection<A, B>
+where
+    A: Predicate<E>,
+    B: Predicate<E>,
+{
+    fn test(&self, element: &E) -> bool {
+        self.0.test(element) && self.1.test(element)
+    }
+}
+
+/// Predicate satisfied by elements not satisfying the wrapped predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Complement<P>(pub P);
+
+impl<E, P> Predicate<E> for Complement<P>
+where
+    P: Predicate<E>,
+{
+    fn test(&self, element: &E) -> bool {
+        !self.0.test(element)
+    }
+}
+
+/// Set of bytes, stored as a bitmap.
+/// ## Notes
+/// Any predicate over bytes can be turned into a set by using [`ByteSet::of`],
+/// which makes testing a byte a single lookup, regardless of how the predicate is composed.
+///
+/// [`ByteSet::of`]: #method.of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteSet([u64; 4]);
+
+impl ByteSet {
+    /// Constructs a new, empty instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self([0; 4])
+    }
+
+    /// Constructs a new instance, holding the bytes satisfying the passed predicate.
+    pub fn of<P>(predicate: P) -> Self
+    where
+        P: Predicate<u8>,
+    {
+        (0..=u8::MAX)
+            .filter(|byte| predicate.test(byte))
+            .fold(Self::new(), Self::with)
+    }
+
+    /// Returns a set holding the passed byte as well.
+    #[must_use]
+    pub fn with(mut self, byte: u8) -> Self {
+        self.0[usize::from(byte / 64)] |= 1 << (byte % 64);
+
+        self
+    }
+
+    /// Returns boolean indicating whether the set holds the passed byte.
+    #[must_use]
+    pub fn contains(&self, byte: u8) -> bool {
+        self.0[usize::from(byte / 64)] & (1 << (byte % 64)) != 0
+    }
+}
+
+impl Predicate<u8> for ByteSet {
+    fn test(&self, element: &u8) -> bool {
+        self.contains(*element)
+    }
+}
+
+macro_rules! impl_classes {
+    ($($(#[$attribute:meta])* $class:ident => |$c:ident| $character:expr, |$b:ident| $byte:expr;)+) => {
+        $(
+            $(#[$attribute])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+            pub struct $class;
+
+            impl Predicate<char> for $class {
+                fn test(&self, $c: &char) -> bool {
+                    $character
+                }
+            }
+
+            impl Predicate<u8> for $class {
+                fn test(&self, $b: &u8) -> bool {
+                    $byte
+                }
+            }
+        )+
+    };
+}
+
+impl_classes! {
+    /// Predicate satisfied by ASCII decimal digits.
+    Digit => |c| c.is_ascii_digit(), |b| b.is_ascii_digit();
+    /// Predicate satisfied by ASCII hexadecimal digits, in both cases.
+    HexDigit => |c| c.is_ascii_hexdigit(), |b| b.is_ascii_hexdigit();
+    /// Predicate satisfied by alphabetic characters (Unicode for `char`, ASCII for `u8`).
+    Alphabetic => |c| c.is_alphabetic(), |b| b.is_ascii_alphabetic();
+    /// Predicate satisfied by alphanumeric characters (Unicode for `char`, ASCII for `u8`).
+    Alphanumeric => |c| c.is_alphanumeric(), |b| b.is_ascii_alphanumeric();
+    /// Predicate satisfied by whitespace (Unicode for `char`, ASCII for `u8`).
+    Whitespace => |c| c.is_whitespace(), |b| b.is_ascii_whitespace();
+    /// Predicate satisfied by characters that may start an identifier: alphabetic ones and the underscore.
+    IdentifierStart => |c| *c == '_' || c.is_alphabetic(), |b| *b == b'_' || b.is_ascii_alphabetic();
+    /// Predicate satisfied by characters that may continue an identifier: alphanumeric ones and the underscore.
+    IdentifierContinue => |c| *c == '_' || c.is_alphanumeric(), |b| *b == b'_' || b.is_ascii_alphanumeric();
+}
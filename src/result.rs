@@ -1,10 +1,20 @@
 //! This module holds all structures used to represend matching results.
 
-use crate::traits::{MatchFail, MatchStatic, MatchWith, MatchWithInRange};
+#[cfg(feature = "std")]
+use crate::traits::{limits, MatchKeywords};
+use crate::{
+    input::Fragment,
+    traits::{
+        MatchBinary, MatchFail, MatchNumber, MatchStatic, MatchStaticIgnoreCase, MatchString,
+        MatchUntil, MatchWith, MatchWithInRange, Recognize, Repetition,
+    },
+};
+
+mod failure;
+pub use failure::*;
 
-/// Represents failed pattern matching result.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct MatchFailed;
+mod sequence;
+pub use sequence::*;
 
 /// Representing the new state of the matching result after transforming.
 pub enum TransformMatch<T, U> {
@@ -27,6 +37,18 @@ pub enum TransformMappedMatch<T, U, V> {
     Full(T, U, V),
 }
 
+/// Describes whether a separated list may or must end with a separator.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Trailing {
+    /// Indicates that a separator which isn't followed by an item is left unmatched.
+    Forbidden,
+    /// Indicates that a separator which isn't followed by an item is matched as well.
+    Allowed,
+    /// Indicates that every item has to be followed by a separator.
+    Required,
+}
+
 impl<T, U> From<TransformMatch<T, U>> for Match<T, U> {
     fn from(data: TransformMatch<T, U>) -> Self {
         use TransformMatch::{Failed, Full, OnlyRest};
@@ -56,7 +78,7 @@ impl<T, U, V> From<TransformMappedMatch<T, U, V>> for MappedMatch<T, U, V> {
 #[must_use]
 pub struct Match<T, U> {
     matched: Option<T>,
-    rest: Option<U>,
+    rest: Result<U, MatchFailed>,
 }
 
 impl<T, U> Match<T, U> {
@@ -64,25 +86,33 @@ impl<T, U> Match<T, U> {
     pub const fn new(matched: Option<T>, rest: U) -> Self {
         Self {
             matched,
-            rest: Some(rest),
+            rest: Ok(rest),
         }
     }
 
     /// Returns boolean indicating whether the pattern was matched.
     /// This returns true when the pattern didn't match.
     pub const fn is_failed(&self) -> bool {
-        matches!(self.rest, None)
+        self.rest.is_err()
+    }
+
+    /// Returns boolean indicating whether matching has stopped because of incomplete input.
+    /// Incomplete matching results are considered failed as well.
+    pub const fn is_incomplete(&self) -> bool {
+        matches!(&self.rest, Err(error) if error.is_incomplete())
+    }
+
+    const fn is_unrecoverable(&self) -> bool {
+        matches!(&self.rest, Err(error) if error.is_unrecoverable())
     }
 
     /// Returns `(Option<_>(matched), rest)` wrapped in `Result`, consuming the object.
     /// # Errors
     /// Returns `Err` when matching has failed.
     pub fn take(self) -> Result<(Option<T>, U), MatchFailed> {
-        if let Some(rest) = self.rest {
-            Ok((self.matched, rest))
-        } else {
-            Err(MatchFailed)
-        }
+        let matched: Option<T> = self.matched;
+
+        self.rest.map(|rest| (matched, rest))
     }
 
     /// Returns a reference to the "matched" part.
@@ -92,7 +122,12 @@ impl<T, U> Match<T, U> {
 
     /// Returns a reference to the "matched" part.
     pub fn rest(&self) -> Option<&U> {
-        self.rest.as_ref()
+        self.rest.as_ref().ok()
+    }
+
+    /// Returns a reference to the failure's description, if matching has failed.
+    pub fn error(&self) -> Option<&MatchFailed> {
+        self.rest.as_ref().err()
     }
 
     /// Tranforms the whole matching result using the passed function.
@@ -103,10 +138,9 @@ impl<T, U> Match<T, U> {
     where
         F: FnOnce(Option<T>, U) -> TransformMatch<M, R>,
     {
-        if let Some(rest) = self.rest {
-            f(self.matched, rest).into()
-        } else {
-            Match::failed()
+        match self.rest {
+            Ok(rest) => f(self.matched, rest).into(),
+            Err(error) => Match::failed_with(error),
         }
     }
 
@@ -118,10 +152,10 @@ impl<T, U> Match<T, U> {
     where
         F: FnOnce(T, U) -> TransformMatch<M, R>,
     {
-        if let Some((matched, rest)) = self.matched.zip(self.rest) {
-            f(matched, rest).into()
-        } else {
-            Match::failed()
+        match (self.matched, self.rest) {
+            (Some(matched), Ok(rest)) => f(matched, rest).into(),
+            (None, Ok(_)) => Match::failed(),
+            (_, Err(error)) => Match::failed_with(error),
         }
     }
 
@@ -131,9 +165,9 @@ impl<T, U> Match<T, U> {
         F: FnOnce(T) -> R,
     {
         match (self.matched, self.rest) {
-            (Some(matched), Some(rest)) => Match::new(Some(f(matched)), rest),
-            (None, Some(rest)) => Match::new(None, rest),
-            (_, None) => Match::failed(),
+            (Some(matched), Ok(rest)) => Match::new(Some(f(matched)), rest),
+            (None, Ok(rest)) => Match::new(None, rest),
+            (_, Err(error)) => Match::failed_with(error),
         }
     }
 
@@ -143,16 +177,16 @@ impl<T, U> Match<T, U> {
         F: FnOnce(U) -> R,
     {
         match (self.matched, self.rest) {
-            (matched, Some(rest)) => Match::new(matched, f(rest)),
-            (_, None) => Match::failed(),
+            (matched, Ok(rest)) => Match::new(matched, f(rest)),
+            (_, Err(error)) => Match::failed_with(error),
         }
     }
 
     /// Converts the matching result into a mapped one.
     pub fn map<V>(self, value: V) -> MappedMatch<T, U, V> {
         match self.rest {
-            Some(rest) => MappedMatch::new(self.matched.zip(Some(value)), rest),
-            None => MappedMatch::failed(),
+            Ok(rest) => MappedMatch::new(self.matched.zip(Some(value)), rest),
+            Err(error) => MappedMatch::failed_with(error),
         }
     }
 
@@ -166,7 +200,7 @@ impl<T, U> Match<T, U> {
     /// This is a short-hand for `to_successful().unwrap()`.
     /// # Panics
     /// This function panics, if the `is_failed` function indicates an "failed" one.
-    #[cfg_attr(not(feature = "no_track_caller"), track_caller)]
+    #[track_caller]
     pub fn unwrap(self) -> (Option<T>, U) {
         (self.matched, self.rest.unwrap())
     }
@@ -175,18 +209,59 @@ impl<T, U> Match<T, U> {
     /// This is a short-hand for `to_successful().expect("...")`.
     /// # Panics
     /// This function panics, if the `is_failed` function indicates an "failed" one.
-    #[cfg_attr(not(feature = "no_track_caller"), track_caller)]
+    #[track_caller]
     pub fn expect(self, msg: &str) -> (Option<T>, U) {
         (self.matched, self.rest.expect(msg))
     }
 
+    /// Matches using the passed function without consuming any input, i.e. positive lookahead.
+    /// The match result is returned unchanged when the function succeeds, while its failure is forwarded otherwise.
+    pub fn peek<F, R, X>(self, f: F) -> Self
+    where
+        U: Clone,
+        F: FnOnce(&Option<T>, U) -> R,
+        R: Into<Match<X, U>>,
+    {
+        match &self.rest {
+            Ok(rest) => match f(&self.matched, rest.clone()).into().rest {
+                Ok(_) => self,
+                Err(error) => Self::failed_with(error),
+            },
+            Err(_) => self,
+        }
+    }
+
+    /// Matches using the passed function without consuming any input, i.e. negative lookahead.
+    /// The match result is returned unchanged when the function fails, while it fails with
+    /// `Found::Unexpected` at the position of the "rest" part otherwise.
+    /// Unrecoverable failures of the function, like ones caused by incomplete input, are forwarded.
+    pub fn not<F, R, X>(self, f: F) -> Self
+    where
+        U: Fragment,
+        F: FnOnce(&Option<T>, U) -> R,
+        R: Into<Match<X, U>>,
+    {
+        match &self.rest {
+            Ok(rest) => match f(&self.matched, *rest).into().rest {
+                Ok(_) => Self::failed_with(MatchFailed::new(
+                    rest.length(),
+                    Expected::Unknown,
+                    Found::Unexpected,
+                )),
+                Err(error) if error.is_unrecoverable() => Self::failed_with(error),
+                Err(_) => self,
+            },
+            Err(_) => self,
+        }
+    }
+
     /// Asserts that a certain condition is met.
     /// The "matched" and "rest" parts are passed by reference.
     pub fn assert<F>(self, f: F) -> Self
     where
         F: FnOnce(&Option<T>, &U) -> bool,
     {
-        if let Some(rest) = &self.rest {
+        if let Ok(rest) = &self.rest {
             if f(&self.matched, rest) {
                 self
             } else {
@@ -204,7 +279,7 @@ impl<T, U> Match<T, U> {
     where
         F: FnOnce(&Option<T>, &U),
     {
-        if let Some(rest) = &self.rest {
+        if let Ok(rest) = &self.rest {
             f(&self.matched, rest);
 
             self
@@ -220,16 +295,16 @@ impl<T, U> Match<T, U> {
         F: FnOnce(Option<T>, U) -> R,
         R: Into<Self>,
     {
-        if let Some(rest) = self.rest {
-            self.rest = f(self.matched.clone(), rest).into().rest;
+        match self.rest {
+            Ok(rest) => match f(self.matched.clone(), rest).into().rest {
+                Ok(rest) => {
+                    self.rest = Ok(rest);
 
-            if self.is_failed() {
-                Self::failed()
-            } else {
-                self
-            }
-        } else {
-            Self::failed()
+                    self
+                }
+                Err(error) => Self::failed_with(error),
+            },
+            Err(error) => Self::failed_with(error),
         }
     }
 
@@ -239,16 +314,18 @@ impl<T, U> Match<T, U> {
         F: FnOnce(&Option<T>, U) -> R,
         R: Into<Self>,
     {
-        if let Some(rest) = self.rest {
-            self.rest = f(&self.matched, rest).into().rest;
+        match self.rest {
+            Ok(rest) => {
+                self.rest = f(&self.matched, rest).into().rest;
 
-            self
-        } else {
-            Self::failed()
+                self
+            }
+            Err(error) => Self::failed_with(error),
         }
     }
 
     /// If matching fails, the failure is ignored and the original one is forwarded. Otherwise, the matching result is forwarded.
+    /// Unrecoverable failures, like ones caused by incomplete input, are always forwarded.
     pub fn optional<F, R>(self, f: F) -> Self
     where
         T: Clone,
@@ -256,16 +333,17 @@ impl<T, U> Match<T, U> {
         F: FnOnce(Option<T>, U) -> R,
         R: Into<Self>,
     {
-        if let Some(rest) = self.rest.clone() {
-            let result: Self = f(self.matched.clone(), rest).into();
-
-            if result.is_failed() {
-                self
-            } else {
-                result
+        match self.rest.clone() {
+            Ok(rest) => {
+                let result: Self = f(self.matched.clone(), rest).into();
+
+                if result.is_failed() && !result.is_unrecoverable() {
+                    self
+                } else {
+                    result
+                }
             }
-        } else {
-            Self::failed()
+            Err(error) => Self::failed_with(error),
         }
     }
 
@@ -275,16 +353,17 @@ impl<T, U> Match<T, U> {
         F: FnOnce(&Option<T>, &U) -> R,
         R: Into<Self>,
     {
-        if let Some(rest) = &self.rest {
-            let result: Self = f(&self.matched, rest).into();
-
-            if result.is_failed() {
-                self
-            } else {
-                result
+        match &self.rest {
+            Ok(rest) => {
+                let result: Self = f(&self.matched, rest).into();
+
+                if result.is_failed() && !result.is_unrecoverable() {
+                    self
+                } else {
+                    result
+                }
             }
-        } else {
-            Self::failed()
+            Err(error) => Self::failed_with(*error),
         }
     }
 
@@ -298,79 +377,174 @@ impl<T, U> Match<T, U> {
     {
         CollectingMatch::from(self)
     }
+
+    /// Converts current match into a sequence that accumulates the "matched" parts in a tuple.
+    /// The sequence starts from the "rest" part, so the current "matched" part is discarded.
+    pub fn into_sequence(self) -> SequenceMatch<(), U> {
+        match self.rest {
+            Ok(rest) => SequenceMatch::new(rest),
+            Err(error) => SequenceMatch::failed_with(error),
+        }
+    }
 }
 
 impl<T, U> MatchFail for Match<T, U> {
     fn failed() -> Self {
+        Self::failed_with(MatchFailed::default())
+    }
+
+    fn failed_with(error: MatchFailed) -> Self {
         Self {
             matched: None,
-            rest: None,
+            rest: Err(error),
+        }
+    }
+}
+
+impl<F, R, W, V> MatchBinary<F, R> for Match<W, V>
+where
+    R: MatchFail,
+    V: MatchBinary<F, R>,
+{
+    fn match_binary(self, format: F) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_binary(format),
+            Err(error) => R::failed_with(error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, R, W, V> MatchKeywords<K, R> for Match<W, V>
+where
+    R: MatchFail,
+    V: MatchKeywords<K, R>,
+{
+    fn match_keywords(self, keywords: K) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_keywords(keywords),
+            Err(error) => R::failed_with(error),
         }
     }
 }
 
-impl<E, T, R, U, V> MatchStatic<E, T, R> for Match<U, V>
+impl<F, R, W, V> MatchNumber<F, R> for Match<W, V>
+where
+    R: MatchFail,
+    V: MatchNumber<F, R>,
+{
+    fn match_number(self, format: F) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_number(format),
+            Err(error) => R::failed_with(error),
+        }
+    }
+}
+
+impl<E, T, R, W, V> MatchStatic<E, T, R> for Match<W, V>
 where
     R: MatchFail,
     V: MatchStatic<E, T, R>,
 {
     fn match_static(self, pattern: T) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_static(pattern)
-        } else {
-            R::failed()
+        match self.rest {
+            Ok(rest) => rest.match_static(pattern),
+            Err(error) => R::failed_with(error),
         }
     }
 }
 
-impl<F, R, H, U, V> MatchWith<F, R, H> for Match<U, V>
+impl<E, T, R, W, V> MatchStaticIgnoreCase<E, T, R> for Match<W, V>
 where
     R: MatchFail,
-    V: MatchWith<F, R, H>,
+    V: MatchStaticIgnoreCase<E, T, R>,
 {
-    fn match_with(self, pattern: F) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_with(pattern)
-        } else {
-            R::failed()
+    fn match_static_ignore_case(self, pattern: T) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_static_ignore_case(pattern),
+            Err(error) => R::failed_with(error),
         }
     }
 }
 
-impl<N, F, R, H1, H2, U, V> MatchWithInRange<N, F, R, H1, H2> for Match<U, V>
+impl<F, R, W, V> MatchString<F, R> for Match<W, V>
 where
     R: MatchFail,
-    V: MatchWithInRange<N, F, R, H1, H2>,
+    V: MatchString<F, R>,
 {
-    fn match_min_with(self, minimum: N, pattern: F) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_min_with(minimum, pattern)
-        } else {
-            R::failed()
+    fn match_string(self, format: F) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_string(format),
+            Err(error) => R::failed_with(error),
         }
     }
+}
 
-    fn match_max_with(self, maximum: N, pattern: F) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_max_with(maximum, pattern)
-        } else {
-            R::failed()
+impl<E, T, R, W, V> MatchUntil<E, T, R> for Match<W, V>
+where
+    R: MatchFail,
+    V: MatchUntil<E, T, R>,
+{
+    fn match_until(self, pattern: T) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_until(pattern),
+            Err(error) => R::failed_with(error),
         }
     }
 
-    fn match_min_max_with(self, minimum: N, maximum: N, pattern: F) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_min_max_with(minimum, maximum, pattern)
-        } else {
-            R::failed()
+    fn match_until_including(self, pattern: T) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_until_including(pattern),
+            Err(error) => R::failed_with(error),
         }
     }
 
-    fn match_exact_with(self, count: N, pattern: F) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_exact_with(count, pattern)
-        } else {
-            R::failed()
+    fn match_until_found(self, pattern: T) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_until_found(pattern),
+            Err(error) => R::failed_with(error),
+        }
+    }
+}
+
+impl<F, R, H, W, V> MatchWith<F, R, H> for Match<W, V>
+where
+    R: MatchFail,
+    V: MatchWith<F, R, H>,
+{
+    fn match_with(self, pattern: F) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_with(pattern),
+            Err(error) => R::failed_with(error),
+        }
+    }
+}
+
+impl<F, R, H1, H2, W, V> MatchWithInRange<F, R, H1, H2> for Match<W, V>
+where
+    R: MatchFail,
+    V: MatchWithInRange<F, R, H1, H2>,
+{
+    fn match_range_with<B>(self, range: B, pattern: F) -> R
+    where
+        B: Repetition,
+    {
+        match self.rest {
+            Ok(rest) => rest.match_range_with(range, pattern),
+            Err(error) => R::failed_with(error),
+        }
+    }
+}
+
+impl<F, R, H, W, V> Recognize<F, R, H> for Match<W, V>
+where
+    R: MatchFail,
+    V: Recognize<F, R, H>,
+{
+    fn recognize(self, f: F) -> R {
+        match self.rest {
+            Ok(rest) => rest.recognize(f),
+            Err(error) => R::failed_with(error),
         }
     }
 }
@@ -380,7 +554,7 @@ where
 #[must_use]
 pub struct MappedMatch<T, U, V> {
     matched: Option<(T, V)>,
-    rest: Option<U>,
+    rest: Result<U, MatchFailed>,
 }
 
 impl<T, U, V> MappedMatch<T, U, V> {
@@ -388,25 +562,33 @@ impl<T, U, V> MappedMatch<T, U, V> {
     pub const fn new(matched: Option<(T, V)>, rest: U) -> Self {
         Self {
             matched,
-            rest: Some(rest),
+            rest: Ok(rest),
         }
     }
 
     /// Returns boolean indicating whether the pattern was matched.
     /// This returns true when the pattern didn't match.
     pub const fn is_failed(&self) -> bool {
-        matches!(self.rest, None)
+        self.rest.is_err()
+    }
+
+    /// Returns boolean indicating whether matching has stopped because of incomplete input.
+    /// Incomplete matching results are considered failed as well.
+    pub const fn is_incomplete(&self) -> bool {
+        matches!(&self.rest, Err(error) if error.is_incomplete())
+    }
+
+    const fn is_unrecoverable(&self) -> bool {
+        matches!(&self.rest, Err(error) if error.is_unrecoverable())
     }
 
     /// Returns `(Option<_>(matched, mapped), rest)` wrapped in `Result`, consuming the object.
     /// # Errors
     /// Returns `Err` when matching has failed.
     pub fn take(self) -> Result<(Option<(T, V)>, U), MatchFailed> {
-        if let Some(rest) = self.rest {
-            Ok((self.matched, rest))
-        } else {
-            Err(MatchFailed)
-        }
+        let matched: Option<(T, V)> = self.matched;
+
+        self.rest.map(|rest| (matched, rest))
     }
 
     /// Returns a reference to the "matched" part.
@@ -420,7 +602,12 @@ impl<T, U, V> MappedMatch<T, U, V> {
 
     /// Returns a reference to the "matched" part.
     pub fn rest(&self) -> Option<&U> {
-        self.rest.as_ref()
+        self.rest.as_ref().ok()
+    }
+
+    /// Returns a reference to the failure's description, if matching has failed.
+    pub fn error(&self) -> Option<&MatchFailed> {
+        self.rest.as_ref().err()
     }
 
     /// Returns a reference to the "matched" part.
@@ -440,10 +627,9 @@ impl<T, U, V> MappedMatch<T, U, V> {
     where
         F: FnOnce(Option<(T, V)>, U) -> TransformMappedMatch<M, R, Q>,
     {
-        if let Some(rest) = self.rest {
-            f(self.matched, rest).into()
-        } else {
-            MappedMatch::failed()
+        match self.rest {
+            Ok(rest) => f(self.matched, rest).into(),
+            Err(error) => MappedMatch::failed_with(error),
         }
     }
 
@@ -455,10 +641,10 @@ impl<T, U, V> MappedMatch<T, U, V> {
     where
         F: FnOnce(T, U, V) -> TransformMappedMatch<M, R, Q>,
     {
-        if let Some(((matched, mapped), rest)) = self.matched.zip(self.rest) {
-            f(matched, rest, mapped).into()
-        } else {
-            MappedMatch::failed()
+        match (self.matched, self.rest) {
+            (Some((matched, mapped)), Ok(rest)) => f(matched, rest, mapped).into(),
+            (None, Ok(_)) => MappedMatch::failed(),
+            (_, Err(error)) => MappedMatch::failed_with(error),
         }
     }
 
@@ -468,11 +654,11 @@ impl<T, U, V> MappedMatch<T, U, V> {
         F: FnOnce(T) -> R,
     {
         match (self.matched, self.rest) {
-            (Some((matched, mapped)), Some(rest)) => {
+            (Some((matched, mapped)), Ok(rest)) => {
                 MappedMatch::new(Some((f(matched), mapped)), rest)
             }
-            (None, Some(rest)) => MappedMatch::new(None, rest),
-            (_, None) => MappedMatch::failed(),
+            (None, Ok(rest)) => MappedMatch::new(None, rest),
+            (_, Err(error)) => MappedMatch::failed_with(error),
         }
     }
 
@@ -482,8 +668,8 @@ impl<T, U, V> MappedMatch<T, U, V> {
         F: FnOnce(U) -> R,
     {
         match (self.matched, self.rest) {
-            (matched, Some(rest)) => MappedMatch::new(matched, f(rest)),
-            (_, None) => MappedMatch::failed(),
+            (matched, Ok(rest)) => MappedMatch::new(matched, f(rest)),
+            (_, Err(error)) => MappedMatch::failed_with(error),
         }
     }
 
@@ -493,11 +679,11 @@ impl<T, U, V> MappedMatch<T, U, V> {
         F: FnOnce(V) -> R,
     {
         match (self.matched, self.rest) {
-            (Some((matched, mapped)), Some(rest)) => {
+            (Some((matched, mapped)), Ok(rest)) => {
                 MappedMatch::new(Some((matched, f(mapped))), rest)
             }
-            (None, Some(rest)) => MappedMatch::new(None, rest),
-            (_, None) => MappedMatch::failed(),
+            (None, Ok(rest)) => MappedMatch::new(None, rest),
+            (_, Err(error)) => MappedMatch::failed_with(error),
         }
     }
 
@@ -508,12 +694,12 @@ impl<T, U, V> MappedMatch<T, U, V> {
         F: FnOnce(V),
     {
         match (self.matched, self.rest) {
-            (Some((matched, mapped)), Some(rest)) => {
+            (Some((matched, mapped)), Ok(rest)) => {
                 f(mapped);
                 Match::new(Some(matched), rest)
             }
-            (None, Some(rest)) => Match::new(None, rest),
-            (_, None) => Match::failed(),
+            (None, Ok(rest)) => Match::new(None, rest),
+            (_, Err(error)) => Match::failed_with(error),
         }
     }
 
@@ -527,7 +713,7 @@ impl<T, U, V> MappedMatch<T, U, V> {
     /// This is a short-hand for `to_successful().unwrap()`.
     /// # Panics
     /// This function panics, if the `is_failed` function indicates an "failed" one.
-    #[cfg_attr(not(feature = "no_track_caller"), track_caller)]
+    #[track_caller]
     pub fn unwrap(self) -> (Option<(T, V)>, U) {
         (self.matched, self.rest.unwrap())
     }
@@ -536,18 +722,59 @@ impl<T, U, V> MappedMatch<T, U, V> {
     /// This is a short-hand for `to_successful().expect("...")`.
     /// # Panics
     /// This function panics, if the `is_failed` function indicates an "failed" one.
-    #[cfg_attr(not(feature = "no_track_caller"), track_caller)]
+    #[track_caller]
     pub fn expect(self, msg: &str) -> (Option<(T, V)>, U) {
         (self.matched, self.rest.expect(msg))
     }
 
+    /// Matches using the passed function without consuming any input, i.e. positive lookahead.
+    /// The match result is returned unchanged when the function succeeds, while its failure is forwarded otherwise.
+    pub fn peek<F, R, X>(self, f: F) -> Self
+    where
+        U: Clone,
+        F: FnOnce(&Option<(T, V)>, U) -> R,
+        R: Into<Match<X, U>>,
+    {
+        match &self.rest {
+            Ok(rest) => match f(&self.matched, rest.clone()).into().rest {
+                Ok(_) => self,
+                Err(error) => Self::failed_with(error),
+            },
+            Err(_) => self,
+        }
+    }
+
+    /// Matches using the passed function without consuming any input, i.e. negative lookahead.
+    /// The match result is returned unchanged when the function fails, while it fails with
+    /// `Found::Unexpected` at the position of the "rest" part otherwise.
+    /// Unrecoverable failures of the function, like ones caused by incomplete input, are forwarded.
+    pub fn not<F, R, X>(self, f: F) -> Self
+    where
+        U: Fragment,
+        F: FnOnce(&Option<(T, V)>, U) -> R,
+        R: Into<Match<X, U>>,
+    {
+        match &self.rest {
+            Ok(rest) => match f(&self.matched, *rest).into().rest {
+                Ok(_) => Self::failed_with(MatchFailed::new(
+                    rest.length(),
+                    Expected::Unknown,
+                    Found::Unexpected,
+                )),
+                Err(error) if error.is_unrecoverable() => Self::failed_with(error),
+                Err(_) => self,
+            },
+            Err(_) => self,
+        }
+    }
+
     /// Asserts that a certain condition is met.
     /// The "matched" and "rest" parts are passed by reference.
     pub fn assert<F>(self, f: F) -> Self
     where
         F: FnOnce(&Option<(T, V)>, &U) -> bool,
     {
-        if let Some(rest) = &self.rest {
+        if let Ok(rest) = &self.rest {
             if f(&self.matched, rest) {
                 self
             } else {
@@ -565,7 +792,7 @@ impl<T, U, V> MappedMatch<T, U, V> {
     where
         F: FnOnce(&Option<(T, V)>, &U),
     {
-        if let Some(rest) = &self.rest {
+        if let Ok(rest) = &self.rest {
             f(&self.matched, rest);
 
             self
@@ -581,16 +808,16 @@ impl<T, U, V> MappedMatch<T, U, V> {
         F: FnOnce(Option<(T, V)>, U) -> R,
         R: Into<Self>,
     {
-        if let Some(rest) = self.rest {
-            self.rest = f(self.matched.clone(), rest).into().rest;
+        match self.rest {
+            Ok(rest) => match f(self.matched.clone(), rest).into().rest {
+                Ok(rest) => {
+                    self.rest = Ok(rest);
 
-            if self.is_failed() {
-                Self::failed()
-            } else {
-                self
-            }
-        } else {
-            Self::failed()
+                    self
+                }
+                Err(error) => Self::failed_with(error),
+            },
+            Err(error) => Self::failed_with(error),
         }
     }
 
@@ -600,16 +827,18 @@ impl<T, U, V> MappedMatch<T, U, V> {
         F: FnOnce(&Option<(T, V)>, U) -> R,
         R: Into<Self>,
     {
-        if let Some(rest) = self.rest {
-            self.rest = f(&self.matched, rest).into().rest;
+        match self.rest {
+            Ok(rest) => {
+                self.rest = f(&self.matched, rest).into().rest;
 
-            self
-        } else {
-            Self::failed()
+                self
+            }
+            Err(error) => Self::failed_with(error),
         }
     }
 
     /// If matching fails, the failure is ignored and the original one is forwarded. Otherwise, the matching result is forwarded.
+    /// Unrecoverable failures, like ones caused by incomplete input, are always forwarded.
     pub fn optional<F, R>(self, f: F) -> Self
     where
         (T, V): Clone,
@@ -617,16 +846,17 @@ impl<T, U, V> MappedMatch<T, U, V> {
         F: FnOnce(Option<(T, V)>, U) -> R,
         R: Into<Self>,
     {
-        if let Some(rest) = self.rest.clone() {
-            let result: Self = f(self.matched.clone(), rest).into();
-
-            if result.is_failed() {
-                self
-            } else {
-                result
+        match self.rest.clone() {
+            Ok(rest) => {
+                let result: Self = f(self.matched.clone(), rest).into();
+
+                if result.is_failed() && !result.is_unrecoverable() {
+                    self
+                } else {
+                    result
+                }
             }
-        } else {
-            Self::failed()
+            Err(error) => Self::failed_with(error),
         }
     }
 
@@ -636,16 +866,17 @@ impl<T, U, V> MappedMatch<T, U, V> {
         F: FnOnce(&Option<(T, V)>, &U) -> R,
         R: Into<Self>,
     {
-        if let Some(rest) = &self.rest {
-            let result: Self = f(&self.matched, rest).into();
-
-            if result.is_failed() {
-                self
-            } else {
-                result
+        match &self.rest {
+            Ok(rest) => {
+                let result: Self = f(&self.matched, rest).into();
+
+                if result.is_failed() && !result.is_unrecoverable() {
+                    self
+                } else {
+                    result
+                }
             }
-        } else {
-            Self::failed()
+            Err(error) => Self::failed_with(*error),
         }
     }
 
@@ -664,142 +895,279 @@ impl<T, U, V> MappedMatch<T, U, V> {
 
 impl<T, U, V> MatchFail for MappedMatch<T, U, V> {
     fn failed() -> Self {
+        Self::failed_with(MatchFailed::default())
+    }
+
+    fn failed_with(error: MatchFailed) -> Self {
         Self {
             matched: None,
-            rest: None,
+            rest: Err(error),
         }
     }
 }
 
-impl<E, T, R, U, V, Q> MatchStatic<E, T, R> for MappedMatch<U, V, Q>
+impl<F, R, W, X, Q> MatchBinary<F, R> for MappedMatch<W, X, Q>
 where
     R: MatchFail,
-    V: MatchStatic<E, T, R>,
+    X: MatchBinary<F, R>,
 {
-    fn match_static(self, pattern: T) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_static(pattern)
-        } else {
-            R::failed()
+    fn match_binary(self, format: F) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_binary(format),
+            Err(error) => R::failed_with(error),
         }
     }
 }
 
-impl<F, R, H, U, V, Q> MatchWith<F, R, H> for MappedMatch<U, V, Q>
+#[cfg(feature = "std")]
+impl<K, R, W, X, Q> MatchKeywords<K, R> for MappedMatch<W, X, Q>
 where
     R: MatchFail,
-    V: MatchWith<F, R, H>,
+    X: MatchKeywords<K, R>,
 {
-    fn match_with(self, pattern: F) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_with(pattern)
-        } else {
-            R::failed()
+    fn match_keywords(self, keywords: K) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_keywords(keywords),
+            Err(error) => R::failed_with(error),
         }
     }
 }
 
-impl<N, F, R, H1, H2, U, V, Q> MatchWithInRange<N, F, R, H1, H2> for MappedMatch<U, V, Q>
+impl<F, R, W, X, Q> MatchNumber<F, R> for MappedMatch<W, X, Q>
 where
     R: MatchFail,
-    V: MatchWithInRange<N, F, R, H1, H2>,
+    X: MatchNumber<F, R>,
 {
-    fn match_min_with(self, minimum: N, pattern: F) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_min_with(minimum, pattern)
-        } else {
-            R::failed()
+    fn match_number(self, format: F) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_number(format),
+            Err(error) => R::failed_with(error),
         }
     }
+}
 
-    fn match_max_with(self, maximum: N, pattern: F) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_max_with(maximum, pattern)
-        } else {
-            R::failed()
+impl<E, T, R, W, X, Q> MatchStatic<E, T, R> for MappedMatch<W, X, Q>
+where
+    R: MatchFail,
+    X: MatchStatic<E, T, R>,
+{
+    fn match_static(self, pattern: T) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_static(pattern),
+            Err(error) => R::failed_with(error),
         }
     }
+}
 
-    fn match_min_max_with(self, minimum: N, maximum: N, pattern: F) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_min_max_with(minimum, maximum, pattern)
-        } else {
-            R::failed()
+impl<E, T, R, W, X, Q> MatchStaticIgnoreCase<E, T, R> for MappedMatch<W, X, Q>
+where
+    R: MatchFail,
+    X: MatchStaticIgnoreCase<E, T, R>,
+{
+    fn match_static_ignore_case(self, pattern: T) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_static_ignore_case(pattern),
+            Err(error) => R::failed_with(error),
         }
     }
+}
 
-    fn match_exact_with(self, count: N, pattern: F) -> R {
-        if let Some(rest) = self.rest {
-            rest.match_exact_with(count, pattern)
-        } else {
-            R::failed()
+impl<F, R, W, X, Q> MatchString<F, R> for MappedMatch<W, X, Q>
+where
+    R: MatchFail,
+    X: MatchString<F, R>,
+{
+    fn match_string(self, format: F) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_string(format),
+            Err(error) => R::failed_with(error),
         }
     }
 }
 
-/// Abstracts over match results while collecting them in a `Vec`.
-/// # Notes
-/// This functionality is available only with the `std` feature.
-#[cfg(feature = "std")]
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[must_use]
-pub struct CollectingMatch<T, U> {
-    matches: Vec<T>,
-    rest: Option<U>,
-}
-
-#[cfg(feature = "std")]
-impl<T, U> CollectingMatch<T, U> {
-    /// Returns boolean indicating whether the pattern was matched.
-    /// This returns true when the pattern didn't match.
-    pub fn is_failed(&self) -> bool {
-        self.rest.is_none()
+impl<E, T, R, W, X, Q> MatchUntil<E, T, R> for MappedMatch<W, X, Q>
+where
+    R: MatchFail,
+    X: MatchUntil<E, T, R>,
+{
+    fn match_until(self, pattern: T) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_until(pattern),
+            Err(error) => R::failed_with(error),
+        }
     }
 
-    /// Calls to this method indicate that the sequence is completed and the final result should be returned.
-    /// # Notes
-    /// If any of the matches failed, then the whole sequence is considered failed.
-    /// # Errors
-    /// Returns `Err` when matching has failed.
-    pub fn finalize(self) -> Result<(Vec<T>, U), MatchFailed> {
+    fn match_until_including(self, pattern: T) -> R {
         match self.rest {
-            Some(rest) => Ok((self.matches, rest)),
-            None => Err(MatchFailed),
+            Ok(rest) => rest.match_until_including(pattern),
+            Err(error) => R::failed_with(error),
         }
     }
 
-    /// Returns inner state.
-    /// This is a short-hand for `finalize().unwrap()`.
-    /// # Panics
-    /// This function panics, if the `is_failed` function indicates an "failed" one.
-    #[cfg_attr(not(feature = "no_track_caller"), track_caller)]
-    pub fn unwrap(self) -> (Vec<T>, U) {
-        self.finalize().unwrap()
+    fn match_until_found(self, pattern: T) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_until_found(pattern),
+            Err(error) => R::failed_with(error),
+        }
     }
+}
 
-    /// Returns inner state.
-    /// This is a short-hand for `finalize().expect("...")`.
-    /// # Panics
-    /// This function panics, if the `is_failed` function indicates an "failed" one.
-    #[cfg_attr(not(feature = "no_track_caller"), track_caller)]
+impl<F, R, H, W, X, Q> MatchWith<F, R, H> for MappedMatch<W, X, Q>
+where
+    R: MatchFail,
+    X: MatchWith<F, R, H>,
+{
+    fn match_with(self, pattern: F) -> R {
+        match self.rest {
+            Ok(rest) => rest.match_with(pattern),
+            Err(error) => R::failed_with(error),
+        }
+    }
+}
+
+impl<F, R, H1, H2, W, X, Q> MatchWithInRange<F, R, H1, H2> for MappedMatch<W, X, Q>
+where
+    R: MatchFail,
+    X: MatchWithInRange<F, R, H1, H2>,
+{
+    fn match_range_with<B>(self, range: B, pattern: F) -> R
+    where
+        B: Repetition,
+    {
+        match self.rest {
+            Ok(rest) => rest.match_range_with(range, pattern),
+            Err(error) => R::failed_with(error),
+        }
+    }
+}
+
+impl<F, R, H, W, X, Q> Recognize<F, R, H> for MappedMatch<W, X, Q>
+where
+    R: MatchFail,
+    X: Recognize<F, R, H>,
+{
+    fn recognize(self, f: F) -> R {
+        match self.rest {
+            Ok(rest) => rest.recognize(f),
+            Err(error) => R::failed_with(error),
+        }
+    }
+}
+
+/// Abstracts over match results while collecting them in a `Vec`.
+/// # Notes
+/// This functionality is available only with the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[must_use]
+pub struct CollectingMatch<T, U> {
+    matches: Vec<T>,
+    rest: Result<U, MatchFailed>,
+}
+
+#[cfg(feature = "std")]
+impl<T, U> CollectingMatch<T, U> {
+    /// Returns boolean indicating whether the pattern was matched.
+    /// This returns true when the pattern didn't match.
+    pub fn is_failed(&self) -> bool {
+        self.rest.is_err()
+    }
+
+    /// Returns boolean indicating whether matching has stopped because of incomplete input.
+    /// Incomplete matching results are considered failed as well.
+    pub const fn is_incomplete(&self) -> bool {
+        matches!(&self.rest, Err(error) if error.is_incomplete())
+    }
+
+    const fn is_unrecoverable(&self) -> bool {
+        matches!(&self.rest, Err(error) if error.is_unrecoverable())
+    }
+
+    /// Calls to this method indicate that the sequence is completed and the final result should be returned.
+    /// # Notes
+    /// If any of the matches failed, then the whole sequence is considered failed.
+    /// # Errors
+    /// Returns `Err` when matching has failed.
+    pub fn finalize(self) -> Result<(Vec<T>, U), MatchFailed> {
+        let matches: Vec<T> = self.matches;
+
+        self.rest.map(|rest| (matches, rest))
+    }
+
+    /// Returns inner state.
+    /// This is a short-hand for `finalize().unwrap()`.
+    /// # Panics
+    /// This function panics, if the `is_failed` function indicates an "failed" one.
+    #[track_caller]
+    pub fn unwrap(self) -> (Vec<T>, U) {
+        self.finalize().unwrap()
+    }
+
+    /// Returns inner state.
+    /// This is a short-hand for `finalize().expect("...")`.
+    /// # Panics
+    /// This function panics, if the `is_failed` function indicates an "failed" one.
+    #[track_caller]
     pub fn expect(self, msg: &str) -> (Vec<T>, U) {
         self.finalize().expect(msg)
     }
 
+    /// Matches using the passed function without consuming any input, i.e. positive lookahead.
+    /// The match result is returned unchanged when the function succeeds, while its failure is forwarded otherwise.
+    pub fn peek<F, R, X>(self, f: F) -> Self
+    where
+        U: Clone,
+        F: FnOnce(Option<&T>, U) -> R,
+        R: Into<Match<X, U>>,
+    {
+        match &self.rest {
+            Ok(rest) => match f(self.matches.last(), rest.clone()).into().rest {
+                Ok(_) => self,
+                Err(error) => Self::failed_with(error),
+            },
+            Err(_) => self,
+        }
+    }
+
+    /// Matches using the passed function without consuming any input, i.e. negative lookahead.
+    /// The match result is returned unchanged when the function fails, while it fails with
+    /// `Found::Unexpected` at the position of the "rest" part otherwise.
+    /// Unrecoverable failures of the function, like ones caused by incomplete input, are forwarded.
+    pub fn not<F, R, X>(self, f: F) -> Self
+    where
+        U: Fragment,
+        F: FnOnce(Option<&T>, U) -> R,
+        R: Into<Match<X, U>>,
+    {
+        match &self.rest {
+            Ok(rest) => match f(self.matches.last(), *rest).into().rest {
+                Ok(_) => Self::failed_with(MatchFailed::new(
+                    rest.length(),
+                    Expected::Unknown,
+                    Found::Unexpected,
+                )),
+                Err(error) if error.is_unrecoverable() => Self::failed_with(error),
+                Err(_) => self,
+            },
+            Err(_) => self,
+        }
+    }
+
     /// Asserts that a certain condition is met.
     /// The "matched" and "rest" parts are passed by reference while the index is passed by value.
     pub fn assert<F>(self, f: F) -> Self
     where
         F: FnOnce(Option<&T>, &U) -> bool,
     {
-        if let Some(rest) = &self.rest {
-            if f(self.matches.last(), rest) {
-                self
-            } else {
-                Self::failed()
+        match &self.rest {
+            Ok(rest) => {
+                if f(self.matches.last(), rest) {
+                    self
+                } else {
+                    Self::failed()
+                }
             }
-        } else {
-            Self::failed()
+            Err(error) => Self::failed_with(*error),
         }
     }
 
@@ -809,12 +1177,13 @@ impl<T, U> CollectingMatch<T, U> {
     where
         F: FnOnce(Option<&T>, &U),
     {
-        if let Some(rest) = &self.rest {
-            f(self.matches.last(), rest);
+        match &self.rest {
+            Ok(rest) => {
+                f(self.matches.last(), rest);
 
-            self
-        } else {
-            Self::failed()
+                self
+            }
+            Err(error) => Self::failed_with(*error),
         }
     }
 
@@ -824,45 +1193,304 @@ impl<T, U> CollectingMatch<T, U> {
         F: FnOnce(Option<&T>, U) -> R,
         R: Into<Match<T, U>>,
     {
-        if let Some(rest) = self.rest {
-            let result: Match<T, U> = f(self.matches.last(), rest).into();
+        match self.rest {
+            Ok(rest) => {
+                let result: Match<T, U> = f(self.matches.last(), rest).into();
 
-            if result.is_failed() {
-                Self::failed()
-            } else {
-                self.rest = result.rest;
+                match result.rest {
+                    Ok(rest) => {
+                        self.rest = Ok(rest);
 
-                if let Some(matched) = result.matched {
-                    self.matches.push(matched);
-                }
+                        if let Some(matched) = result.matched {
+                            self.matches.push(matched);
+                        }
 
-                self
+                        self
+                    }
+                    Err(error) => Self::failed_with(error),
+                }
             }
-        } else {
-            Self::failed()
+            Err(error) => Self::failed_with(error),
         }
     }
 
-    /// Executes the matching function `count` times unless matching has failed.
+    /// Executes the matching function `count` times unless matching has failed, collecting the "matched" parts.
     pub fn repeat<N, F, R>(mut self, mut count: N, mut f: F) -> Self
     where
         N: PartialEq<usize> + core::ops::SubAssign<usize>,
         F: FnMut(Option<&T>, U) -> R,
         R: Into<Match<T, U>>,
+    {
+        while count != 0 && !self.is_failed() {
+            self = self.single(&mut f);
+
+            count -= 1;
+        }
+
+        self
+    }
+
+    /// Executes the matching function as long as it's successful, collecting the "matched" parts.
+    /// # Notes
+    /// Matching stops at the first failure, keeping the state from before it, or once the matching function
+    /// doesn't consume any input, as it would never stop otherwise.
+    /// Unrecoverable failures, like ones caused by incomplete input, are always forwarded.
+    pub fn repeat_while<F, R>(self, f: F) -> Self
+    where
+        U: Clone + PartialEq,
+        F: FnMut(Option<&T>, U) -> R,
+        R: Into<Match<T, U>>,
+    {
+        self.repeat_at_most(None, f).0
+    }
+
+    /// Same as `repeat_while`, but the matching function has to be successful an amount of times within the
+    /// passed range, e.g. `2..`, `..=5`, `3..7` or `4`. Matching stops once the maximum amount is reached.
+    /// # Notes
+    /// A range that accepts no amount at all, e.g. `3..3` or `5..2`, always fails.
+    pub fn repeat_range<B, F, R>(self, range: B, f: F) -> Self
+    where
+        U: Clone + PartialEq,
+        B: Repetition,
+        F: FnMut(Option<&T>, U) -> R,
+        R: Into<Match<T, U>>,
+    {
+        let (minimum, maximum): (usize, Option<usize>) = if let Some(limits) = limits(range) {
+            limits
+        } else {
+            return Self::failed_with(MatchFailed::unknown().with_expected(Expected::Nothing));
+        };
+
+        let (result, count, failure): (Self, usize, Option<MatchFailed>) =
+            self.repeat_at_most(maximum, f);
+
+        if result.is_failed() || minimum <= count {
+            result
+        } else {
+            Self::failed_with(failure.unwrap_or_else(|| {
+                MatchFailed::unknown()
+                    .with_expected(Expected::AtLeast(minimum))
+                    .with_found(Found::Count(count))
+            }))
+        }
+    }
+
+    /// Executes the matching function, collecting the "matched" parts, until the terminator matches.
+    /// The terminator is tried first on each iteration, so it may also match right away. Its "matched" part
+    /// is discarded, while its "rest" part is kept.
+    /// # Notes
+    /// Matching fails when the matching function fails or doesn't consume any input before the terminator
+    /// matches. Unrecoverable failures, like ones caused by incomplete input, are always forwarded.
+    pub fn repeat_until<G, S, M, F, R>(mut self, mut terminator: G, mut f: F) -> Self
+    where
+        U: Clone + PartialEq,
+        G: FnMut(Option<&T>, U) -> S,
+        S: Into<Match<M, U>>,
+        F: FnMut(Option<&T>, U) -> R,
+        R: Into<Match<T, U>>,
     {
         loop {
-            if let Some(rest) = self.rest {
-                if count == 0 {
-                    self.rest = Some(rest);
+            let rest: U = match &self.rest {
+                Ok(rest) => rest.clone(),
+                Err(_) => break self,
+            };
+
+            let end: Match<M, U> = terminator(self.matches.last(), rest.clone()).into();
+
+            let error: MatchFailed = match end.rest {
+                Ok(rest) => {
+                    self.rest = Ok(rest);
 
                     break self;
                 }
+                Err(error) if error.is_unrecoverable() => break Self::failed_with(error),
+                Err(error) => error,
+            };
 
-                self.rest = f(self.matches.last(), rest).into().rest;
+            let result: Match<T, U> = f(self.matches.last(), rest).into();
+
+            match result.rest {
+                Ok(rest) if self.rest.as_ref().ok() != Some(&rest) => {
+                    self.rest = Ok(rest);
+
+                    if let Some(matched) = result.matched {
+                        self.matches.push(matched);
+                    }
+                }
+                Ok(_) => break Self::failed_with(error),
+                Err(error) => break Self::failed_with(error),
+            }
+        }
+    }
+
+    /// Executes the matching function for items separated by the separator, collecting the items' "matched" parts
+    /// while discarding the separators' ones.
+    /// Matching fails when fewer than `minimum` items are matched.
+    /// # Notes
+    /// Matching stops at the first item or separator that fails, as well as once an item and its separator don't
+    /// consume any input. Whether the last separator is matched is controlled by `trailing`.
+    /// Unrecoverable failures, like ones caused by incomplete input, are always forwarded.
+    pub fn separated<G, S, M, F, R>(
+        self,
+        minimum: usize,
+        trailing: Trailing,
+        separator: G,
+        f: F,
+    ) -> Self
+    where
+        U: Clone + PartialEq,
+        G: FnMut(Option<&T>, U) -> S,
+        S: Into<Match<M, U>>,
+        F: FnMut(Option<&T>, U) -> R,
+        R: Into<Match<T, U>>,
+    {
+        self.separated_with(minimum, trailing, separator, f, drop)
+    }
+
+    /// Same as `separated`, but the separators' "matched" parts are collected into the passed vector.
+    pub fn separated_into<G, S, M, F, R>(
+        self,
+        minimum: usize,
+        trailing: Trailing,
+        separators: &mut Vec<M>,
+        separator: G,
+        f: F,
+    ) -> Self
+    where
+        U: Clone + PartialEq,
+        G: FnMut(Option<&T>, U) -> S,
+        S: Into<Match<M, U>>,
+        F: FnMut(Option<&T>, U) -> R,
+        R: Into<Match<T, U>>,
+    {
+        self.separated_with(minimum, trailing, separator, f, |matched| {
+            separators.push(matched);
+        })
+    }
+
+    fn separated_with<G, S, M, F, R, K>(
+        mut self,
+        minimum: usize,
+        trailing: Trailing,
+        mut separator: G,
+        mut f: F,
+        mut keep: K,
+    ) -> Self
+    where
+        U: Clone + PartialEq,
+        G: FnMut(Option<&T>, U) -> S,
+        S: Into<Match<M, U>>,
+        F: FnMut(Option<&T>, U) -> R,
+        R: Into<Match<T, U>>,
+        K: FnMut(M),
+    {
+        let mut count: usize = 0;
+
+        let failure: Option<MatchFailed> = loop {
+            let rest: U = match &self.rest {
+                Ok(rest) => rest.clone(),
+                Err(_) => return self,
+            };
 
-                count -= 1;
+            // Every item, except for the first one, is preceded by a separator.
+            let (separated, matched_separator): (U, Option<M>) = if count == 0 {
+                (rest.clone(), None)
             } else {
-                break Self::failed();
+                let result: Match<M, U> = separator(self.matches.last(), rest.clone()).into();
+
+                match result.rest {
+                    Ok(separated) => (separated, result.matched),
+                    Err(error) if error.is_unrecoverable() || trailing == Trailing::Required => {
+                        return Self::failed_with(error)
+                    }
+                    Err(error) => break Some(error),
+                }
+            };
+
+            let result: Match<T, U> = f(self.matches.last(), separated.clone()).into();
+
+            match result.rest {
+                Ok(next) if next != rest => {
+                    if let Some(matched) = matched_separator {
+                        keep(matched);
+                    }
+
+                    if let Some(matched) = result.matched {
+                        self.matches.push(matched);
+                    }
+
+                    self.rest = Ok(next);
+
+                    count += 1;
+                }
+                Ok(_) => break None,
+                Err(error) if error.is_unrecoverable() => return Self::failed_with(error),
+                Err(error) => {
+                    if count != 0 && trailing != Trailing::Forbidden {
+                        if let Some(matched) = matched_separator {
+                            keep(matched);
+                        }
+
+                        self.rest = Ok(separated);
+                    }
+
+                    break Some(error);
+                }
+            }
+        };
+
+        if minimum <= count {
+            self
+        } else {
+            Self::failed_with(failure.unwrap_or_else(|| {
+                MatchFailed::unknown()
+                    .with_expected(Expected::AtLeast(minimum))
+                    .with_found(Found::Count(count))
+            }))
+        }
+    }
+
+    /// Executes the matching function until it fails, doesn't consume any input or the maximum amount is reached.
+    /// Returns the new state, the amount of successful matches and the recoverable failure that stopped matching.
+    fn repeat_at_most<F, R>(
+        mut self,
+        maximum: Option<usize>,
+        mut f: F,
+    ) -> (Self, usize, Option<MatchFailed>)
+    where
+        U: Clone + PartialEq,
+        F: FnMut(Option<&T>, U) -> R,
+        R: Into<Match<T, U>>,
+    {
+        let mut count: usize = 0;
+
+        loop {
+            if maximum == Some(count) {
+                break (self, count, None);
+            }
+
+            let rest: U = match &self.rest {
+                Ok(rest) => rest.clone(),
+                Err(_) => break (self, count, None),
+            };
+
+            let result: Match<T, U> = f(self.matches.last(), rest).into();
+
+            match result.rest {
+                Ok(rest) if self.rest.as_ref().ok() != Some(&rest) => {
+                    self.rest = Ok(rest);
+
+                    if let Some(matched) = result.matched {
+                        self.matches.push(matched);
+                    }
+
+                    count += 1;
+                }
+                Ok(_) => break (self, count, None),
+                Err(error) if error.is_unrecoverable() => {
+                    break (Self::failed_with(error), count, None)
+                }
+                Err(error) => break (self, count, Some(error)),
             }
         }
     }
@@ -873,32 +1501,35 @@ impl<T, U> CollectingMatch<T, U> {
         F: FnOnce(Option<&T>, U) -> R,
         R: Into<Match<T, U>>,
     {
-        if let Some(rest) = self.rest {
-            self.rest = f(self.matches.last(), rest).into().rest;
+        match self.rest {
+            Ok(rest) => {
+                self.rest = f(self.matches.last(), rest).into().rest;
 
-            self
-        } else {
-            Self::failed()
+                self
+            }
+            Err(error) => Self::failed_with(error),
         }
     }
 
     /// If matching fails, the failure is ignored and the original one is forwarded. Otherwise, the matching result is forwarded.
+    /// Unrecoverable failures, like ones caused by incomplete input, are always forwarded.
     pub fn optional<F, R>(self, f: F) -> Self
     where
         U: Clone,
         F: FnOnce(Option<&T>, U) -> R,
         R: Into<Self>,
     {
-        if let Some(rest) = self.rest.clone() {
-            let result: Self = f(self.matches.last(), rest).into();
-
-            if result.is_failed() {
-                self
-            } else {
-                result
+        match self.rest.clone() {
+            Ok(rest) => {
+                let result: Self = f(self.matches.last(), rest).into();
+
+                if result.is_failed() && !result.is_unrecoverable() {
+                    self
+                } else {
+                    result
+                }
             }
-        } else {
-            Self::failed()
+            Err(error) => Self::failed_with(error),
         }
     }
 
@@ -908,16 +1539,17 @@ impl<T, U> CollectingMatch<T, U> {
         F: FnOnce(Option<&T>, &U) -> R,
         R: Into<Self>,
     {
-        if let Some(rest) = &self.rest {
-            let result: Self = f(self.matches.last(), rest).into();
-
-            if result.is_failed() {
-                self
-            } else {
-                result
+        match &self.rest {
+            Ok(rest) => {
+                let result: Self = f(self.matches.last(), rest).into();
+
+                if result.is_failed() && !result.is_unrecoverable() {
+                    self
+                } else {
+                    result
+                }
             }
-        } else {
-            Self::failed()
+            Err(error) => Self::failed_with(*error),
         }
     }
 }
@@ -925,9 +1557,13 @@ impl<T, U> CollectingMatch<T, U> {
 #[cfg(feature = "std")]
 impl<T, U> MatchFail for CollectingMatch<T, U> {
     fn failed() -> Self {
+        Self::failed_with(MatchFailed::default())
+    }
+
+    fn failed_with(error: MatchFailed) -> Self {
         Self {
             matches: Vec::new(),
-            rest: None,
+            rest: Err(error),
         }
     }
 }
@@ -937,7 +1573,7 @@ impl<T, U> From<U> for CollectingMatch<T, U> {
     fn from(rest: U) -> Self {
         Self {
             matches: Vec::new(),
-            rest: Some(rest),
+            rest: Ok(rest),
         }
     }
 }
@@ -956,32 +1592,116 @@ impl<T, U> From<Match<T, U>> for CollectingMatch<T, U> {
     }
 }
 
+/// Describes how alternatives matching trees choose between branches.
+enum Mode<V> {
+    /// Keeps the first successful branch, skipping the rest.
+    First,
+    /// Keeps the first successful branch, while still evaluating the rest.
+    Exhaustive,
+    /// Keeps the successful branch with the shortest "rest" part, as measured by the function.
+    Longest(fn(&V) -> usize),
+}
+
 /// Represents alternatives matching tree.
 /// ## Notes
 /// When one of the matching branches does *not* fail, all the rest will be skipped as this structure short-circuits when a matching branch is successful.
+/// The same applies when a matching branch fails unrecoverably, e.g. because of incomplete input.
+/// When all the branches fail, their failures are merged, see [`MatchFailed::merge`], while everything the
+/// furthest of them expected is collected by [`expected_set`] and returned together with the result by
+/// [`finalize_merged`].
+/// ## Longest match
+/// After calling the [`longest`] method, every branch is evaluated and the successful one that consumed the most
+/// input is kept instead. When multiple branches consumed the same amount of input, the one added first is kept.
+/// ## Branches
+/// Branches are indexed in the order they were added, starting from zero. The index of the kept branch is
+/// returned by [`finalize_indexed`]. With the `std` feature, the indices of all successful branches are returned
+/// by [`matched_branches`]; calling the [`exhaustive`] method beforehand makes sure every branch is evaluated.
+/// Branches added with [`add_tagged_path`] carry a tag, which is returned for the kept branch by [`finalize_tagged`].
+///
+/// [`MatchFailed::merge`]: struct.MatchFailed.html#method.merge
+/// [`expected_set`]: #method.expected_set
+/// [`finalize_merged`]: #method.finalize_merged
+/// [`longest`]: #method.longest
+/// [`finalize_indexed`]: #method.finalize_indexed
+/// [`matched_branches`]: #method.matched_branches
+/// [`exhaustive`]: #method.exhaustive
+/// [`add_tagged_path`]: #method.add_tagged_path
+/// [`finalize_tagged`]: #method.finalize_tagged
 #[must_use]
-pub struct AlternativesMatch<T, U, V> {
+pub struct AlternativesMatch<T, U, V, X = ()> {
     previous: T,
     matched: Match<U, V>,
+    mode: Mode<V>,
+    branches: usize,
+    index: Option<usize>,
+    tag: Option<X>,
+    expected: ExpectedSet,
+    #[cfg(feature = "std")]
+    matched_branches: Vec<usize>,
 }
 
-impl<T, U, V> AlternativesMatch<T, U, V> {
+impl<T, U, V, X> AlternativesMatch<T, U, V, X> {
     /// Creates new instance.
     pub const fn new(previous: T) -> Self {
         Self {
             previous,
             matched: Match {
                 matched: None,
-                rest: None,
+                rest: Err(MatchFailed::unknown()),
             },
+            mode: Mode::First,
+            branches: 0,
+            index: None,
+            tag: None,
+            expected: ExpectedSet::new(Expected::Unknown),
+            #[cfg(feature = "std")]
+            matched_branches: Vec::new(),
         }
     }
 
+    /// Switches to evaluating every branch and keeping the successful one that consumed the most input.
+    pub fn longest(mut self) -> Self
+    where
+        V: Fragment,
+    {
+        self.mode = Mode::Longest(|input: &V| input.length());
+
+        self
+    }
+
+    /// Switches to evaluating every branch while keeping the first successful one.
+    /// Useful for finding ambiguities together with the `matched_branches` method.
+    pub fn exhaustive(mut self) -> Self {
+        self.mode = Mode::Exhaustive;
+
+        self
+    }
+
     /// Returns true whenever any of the already defined branches has matched.
     pub fn is_matched(&self) -> bool {
         !self.matched.is_failed()
     }
 
+    /// Returns true whenever any of the already defined branches has stopped because of incomplete input.
+    pub const fn is_incomplete(&self) -> bool {
+        self.matched.is_incomplete()
+    }
+
+    /// Returns the indices of all evaluated branches that matched successfully.
+    /// # Notes
+    /// This functionality is available only with the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn matched_branches(&self) -> &[usize] {
+        &self.matched_branches
+    }
+
+    /// Returns descriptions of everything that was expected by the failed branches which reached the furthest.
+    /// The failure returned by `finalize` carries only the first of them, unlike the one returned by
+    /// `finalize_merged`.
+    pub const fn expected_set(&self) -> &ExpectedSet {
+        &self.expected
+    }
+
     /// Adds a separate matching branch.
     pub fn add_path<F, R>(mut self, f: F) -> Self
     where
@@ -989,10 +1709,32 @@ impl<T, U, V> AlternativesMatch<T, U, V> {
         F: FnOnce(T) -> R,
         R: Into<Match<U, V>>,
     {
-        if self.matched.is_failed() {
-            self.matched = f(self.previous.clone()).into();
+        if !self.is_settled() {
+            let result: Match<U, V> = f(self.previous.clone()).into();
+
+            self.choose(result, None);
+        }
+
+        self.branches += 1;
+
+        self
+    }
+
+    /// Adds a separate matching branch, which is identified by the passed tag when kept.
+    pub fn add_tagged_path<F, R>(mut self, tag: X, f: F) -> Self
+    where
+        T: Clone,
+        F: FnOnce(T) -> R,
+        R: Into<Match<U, V>>,
+    {
+        if !self.is_settled() {
+            let result: Match<U, V> = f(self.previous.clone()).into();
+
+            self.choose(result, Some(tag));
         }
 
+        self.branches += 1;
+
         self
     }
 
@@ -1002,10 +1744,14 @@ impl<T, U, V> AlternativesMatch<T, U, V> {
         F: FnOnce(&T) -> R,
         R: Into<Match<U, V>>,
     {
-        if self.matched.is_failed() {
-            self.matched = f(&self.previous).into();
+        if !self.is_settled() {
+            let result: Match<U, V> = f(&self.previous).into();
+
+            self.choose(result, None);
         }
 
+        self.branches += 1;
+
         self
     }
 
@@ -1013,34 +1759,152 @@ impl<T, U, V> AlternativesMatch<T, U, V> {
     pub fn finalize(self) -> Match<U, V> {
         self.matched
     }
+
+    /// Same as `finalize`, but also returns the index of the kept branch, if any has matched.
+    pub fn finalize_indexed(self) -> (Match<U, V>, Option<usize>) {
+        (self.matched, self.index)
+    }
+
+    /// Same as `finalize`, but also returns the tag of the kept branch.
+    /// No tag is returned when no branch has matched or the kept one was added without a tag.
+    pub fn finalize_tagged(self) -> (Match<U, V>, Option<X>) {
+        (self.matched, self.tag)
+    }
+
+    /// Same as `finalize`, but also returns everything that was expected by the failed branches which reached
+    /// the furthest. Together with the failure, it can be displayed using [`AlternativesFailed`].
+    ///
+    /// [`AlternativesFailed`]: struct.AlternativesFailed.html
+    pub fn finalize_merged(self) -> (Match<U, V>, ExpectedSet) {
+        (self.matched, self.expected)
+    }
+
+    /// Returns boolean indicating whether the remaining branches can be skipped.
+    fn is_settled(&self) -> bool {
+        if self.matched.is_unrecoverable() {
+            true
+        } else {
+            matches!(self.mode, Mode::First) && !self.matched.is_failed()
+        }
+    }
+
+    /// Replaces the kept result with the passed one, if it's preferred.
+    /// Recoverable failures are merged with the kept failure instead.
+    fn choose(&mut self, result: Match<U, V>, tag: Option<X>) {
+        if let (Err(current), Err(error)) = (&self.matched.rest, &result.rest) {
+            if !error.is_unrecoverable() {
+                self.expected.merge(current, error);
+                self.matched.rest = Err(current.merge(*error));
+
+                return;
+            }
+        }
+
+        let replace: bool = match (&self.matched.rest, &result.rest, &self.mode) {
+            (_, Err(error), _) => error.is_unrecoverable() || self.matched.is_failed(),
+            (Ok(current), Ok(rest), Mode::Longest(length)) => length(rest) < length(current),
+            (Ok(_), Ok(_), Mode::First | Mode::Exhaustive) => false,
+            (Err(_), Ok(_), _) => true,
+        };
+
+        if !result.is_failed() {
+            #[cfg(feature = "std")]
+            self.matched_branches.push(self.branches);
+
+            if replace {
+                self.index = Some(self.branches);
+                self.tag = tag;
+            }
+        } else if replace {
+            self.index = None;
+            self.tag = None;
+        }
+
+        if replace {
+            self.matched = result;
+        }
+    }
 }
 
-/// Represents alternatives matching tree.
-/// ## Notes
-/// When one of the matching branches does *not* fail, all the rest will be skipped as this structure short-circuits when a matching branch is successful.
+/// Represents alternatives matching tree, which maps a value to the kept result.
+/// Branches are evaluated and chosen the same way as in [`AlternativesMatch`].
+///
+/// [`AlternativesMatch`]: struct.AlternativesMatch.html
 #[must_use]
-pub struct MappedAlternativesMatch<T, U, V, W> {
+pub struct MappedAlternativesMatch<T, U, V, W, X = ()> {
     previous: T,
     matched: MappedMatch<U, V, W>,
+    mode: Mode<V>,
+    branches: usize,
+    index: Option<usize>,
+    tag: Option<X>,
+    expected: ExpectedSet,
+    #[cfg(feature = "std")]
+    matched_branches: Vec<usize>,
 }
 
-impl<T, U, V, W> MappedAlternativesMatch<T, U, V, W> {
+impl<T, U, V, W, X> MappedAlternativesMatch<T, U, V, W, X> {
     /// Creates new instance.
     pub const fn new(previous: T) -> Self {
         Self {
             previous,
             matched: MappedMatch {
                 matched: None,
-                rest: None,
+                rest: Err(MatchFailed::unknown()),
             },
+            mode: Mode::First,
+            branches: 0,
+            index: None,
+            tag: None,
+            expected: ExpectedSet::new(Expected::Unknown),
+            #[cfg(feature = "std")]
+            matched_branches: Vec::new(),
         }
     }
 
+    /// Switches to evaluating every branch and keeping the successful one that consumed the most input.
+    pub fn longest(mut self) -> Self
+    where
+        V: Fragment,
+    {
+        self.mode = Mode::Longest(|input: &V| input.length());
+
+        self
+    }
+
+    /// Switches to evaluating every branch while keeping the first successful one.
+    /// Useful for finding ambiguities together with the `matched_branches` method.
+    pub fn exhaustive(mut self) -> Self {
+        self.mode = Mode::Exhaustive;
+
+        self
+    }
+
     /// Returns true whenever any of the already defined branches has matched.
     pub fn is_matched(&self) -> bool {
         !self.matched.is_failed()
     }
 
+    /// Returns true whenever any of the already defined branches has stopped because of incomplete input.
+    pub const fn is_incomplete(&self) -> bool {
+        self.matched.is_incomplete()
+    }
+
+    /// Returns the indices of all evaluated branches that matched successfully.
+    /// # Notes
+    /// This functionality is available only with the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn matched_branches(&self) -> &[usize] {
+        &self.matched_branches
+    }
+
+    /// Returns descriptions of everything that was expected by the failed branches which reached the furthest.
+    /// The failure returned by `finalize` carries only the first of them, unlike the one returned by
+    /// `finalize_merged`.
+    pub const fn expected_set(&self) -> &ExpectedSet {
+        &self.expected
+    }
+
     /// Adds a separate matching branch.
     pub fn add_path<F, R>(mut self, f: F) -> Self
     where
@@ -1048,10 +1912,32 @@ impl<T, U, V, W> MappedAlternativesMatch<T, U, V, W> {
         F: FnOnce(T) -> R,
         R: Into<MappedMatch<U, V, W>>,
     {
-        if self.matched.is_failed() {
-            self.matched = f(self.previous.clone()).into();
+        if !self.is_settled() {
+            let result: MappedMatch<U, V, W> = f(self.previous.clone()).into();
+
+            self.choose(result, None);
+        }
+
+        self.branches += 1;
+
+        self
+    }
+
+    /// Adds a separate matching branch, which is identified by the passed tag when kept.
+    pub fn add_tagged_path<F, R>(mut self, tag: X, f: F) -> Self
+    where
+        T: Clone,
+        F: FnOnce(T) -> R,
+        R: Into<MappedMatch<U, V, W>>,
+    {
+        if !self.is_settled() {
+            let result: MappedMatch<U, V, W> = f(self.previous.clone()).into();
+
+            self.choose(result, Some(tag));
         }
 
+        self.branches += 1;
+
         self
     }
 
@@ -1061,10 +1947,14 @@ impl<T, U, V, W> MappedAlternativesMatch<T, U, V, W> {
         F: FnOnce(&T) -> R,
         R: Into<MappedMatch<U, V, W>>,
     {
-        if self.matched.is_failed() {
-            self.matched = f(&self.previous).into();
+        if !self.is_settled() {
+            let result: MappedMatch<U, V, W> = f(&self.previous).into();
+
+            self.choose(result, None);
         }
 
+        self.branches += 1;
+
         self
     }
 
@@ -1072,4 +1962,69 @@ impl<T, U, V, W> MappedAlternativesMatch<T, U, V, W> {
     pub fn finalize(self) -> MappedMatch<U, V, W> {
         self.matched
     }
+
+    /// Same as `finalize`, but also returns the index of the kept branch, if any has matched.
+    pub fn finalize_indexed(self) -> (MappedMatch<U, V, W>, Option<usize>) {
+        (self.matched, self.index)
+    }
+
+    /// Same as `finalize`, but also returns the tag of the kept branch.
+    /// No tag is returned when no branch has matched or the kept one was added without a tag.
+    pub fn finalize_tagged(self) -> (MappedMatch<U, V, W>, Option<X>) {
+        (self.matched, self.tag)
+    }
+
+    /// Same as `finalize`, but also returns everything that was expected by the failed branches which reached
+    /// the furthest. Together with the failure, it can be displayed using [`AlternativesFailed`].
+    ///
+    /// [`AlternativesFailed`]: struct.AlternativesFailed.html
+    pub fn finalize_merged(self) -> (MappedMatch<U, V, W>, ExpectedSet) {
+        (self.matched, self.expected)
+    }
+
+    /// Returns boolean indicating whether the remaining branches can be skipped.
+    fn is_settled(&self) -> bool {
+        if self.matched.is_unrecoverable() {
+            true
+        } else {
+            matches!(self.mode, Mode::First) && !self.matched.is_failed()
+        }
+    }
+
+    /// Replaces the kept result with the passed one, if it's preferred.
+    /// Recoverable failures are merged with the kept failure instead.
+    fn choose(&mut self, result: MappedMatch<U, V, W>, tag: Option<X>) {
+        if let (Err(current), Err(error)) = (&self.matched.rest, &result.rest) {
+            if !error.is_unrecoverable() {
+                self.expected.merge(current, error);
+                self.matched.rest = Err(current.merge(*error));
+
+                return;
+            }
+        }
+
+        let replace: bool = match (&self.matched.rest, &result.rest, &self.mode) {
+            (_, Err(error), _) => error.is_unrecoverable() || self.matched.is_failed(),
+            (Ok(current), Ok(rest), Mode::Longest(length)) => length(rest) < length(current),
+            (Ok(_), Ok(_), Mode::First | Mode::Exhaustive) => false,
+            (Err(_), Ok(_), _) => true,
+        };
+
+        if !result.is_failed() {
+            #[cfg(feature = "std")]
+            self.matched_branches.push(self.branches);
+
+            if replace {
+                self.index = Some(self.branches);
+                self.tag = tag;
+            }
+        } else if replace {
+            self.index = None;
+            self.tag = None;
+        }
+
+        if replace {
+            self.matched = result;
+        }
+    }
 }
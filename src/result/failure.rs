@@ -0,0 +1,553 @@
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt::{self, Debug, Display, Formatter},
+};
+
+/// Maximal amount of bytes stored inside a [`Snippet`].
+///
+/// [`Snippet`]: struct.Snippet.html
+pub const SNIPPET_CAPACITY: usize = 24;
+
+/// Short, inline copy of a piece of text.
+/// Used to describe patterns and input fragments without requiring allocations.
+/// ## Notes
+/// Text longer than [`SNIPPET_CAPACITY`] bytes is truncated on a character boundary.
+///
+/// [`SNIPPET_CAPACITY`]: constant.SNIPPET_CAPACITY.html
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Snippet {
+    bytes: [u8; SNIPPET_CAPACITY],
+    length: u8,
+    truncated: bool,
+}
+
+impl Snippet {
+    /// Constructs a new instance by copying the passed text.
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        let mut length: usize = text.len().min(SNIPPET_CAPACITY);
+
+        while !text.is_char_boundary(length) {
+            length -= 1;
+        }
+
+        let mut bytes: [u8; SNIPPET_CAPACITY] = [0; SNIPPET_CAPACITY];
+
+        bytes[..length].copy_from_slice(&text.as_bytes()[..length]);
+
+        Self {
+            bytes,
+            length: u8::try_from(length).unwrap_or(0),
+            truncated: length != text.len(),
+        }
+    }
+
+    /// Returns the stored text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..usize::from(self.length)]).unwrap_or_default()
+    }
+
+    /// Returns boolean indicating whether the original text was longer than the stored one.
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl Debug for Snippet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for Snippet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())?;
+
+        if self.truncated {
+            f.write_str("...")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Describes what the failed pattern expected to find.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Expected {
+    /// Indicates that no information is available.
+    #[default]
+    Unknown,
+    /// Indicates a static textual pattern, as passed to [`MatchStatic::match_static`].
+    ///
+    /// [`MatchStatic::match_static`]: ../traits/trait.MatchStatic.html#tymethod.match_static
+    Text(Snippet),
+    /// Indicates a static pattern of elements with the given length, as passed to [`MatchStatic::match_static`].
+    ///
+    /// [`MatchStatic::match_static`]: ../traits/trait.MatchStatic.html#tymethod.match_static
+    Elements(usize),
+    /// Indicates at least the given amount of elements matching a "dynamic" pattern.
+    AtLeast(usize),
+    /// Indicates between the given minimum and maximum amounts of elements matching a "dynamic" pattern.
+    Between(usize, usize),
+    /// Indicates exactly the given amount of elements matching a "dynamic" pattern.
+    Exactly(usize),
+    /// Indicates a forward-declared rule that was never defined.
+    Undefined,
+    /// Indicates a range of amounts that accepts no amount at all, e.g. `3..3` or `5..2`, so nothing can match.
+    Nothing,
+    /// Indicates a digit in the given radix, as part of a number.
+    Digit(u32),
+    /// Indicates a number that fits within its type.
+    Number,
+    /// Indicates a known escape sequence, as part of a string literal.
+    Escape,
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown => f.write_str("unknown"),
+            Self::Text(text) => write!(f, "`{text}`"),
+            Self::Elements(length) => write!(f, "pattern of {length} element(s)"),
+            Self::AtLeast(minimum) => write!(f, "at least {minimum} element(s)"),
+            Self::Between(minimum, maximum) => {
+                write!(f, "between {minimum} and {maximum} element(s)")
+            }
+            Self::Exactly(count) => write!(f, "exactly {count} element(s)"),
+            Self::Undefined => f.write_str("defined rule"),
+            Self::Nothing => f.write_str("nothing (empty range of amounts)"),
+            Self::Digit(radix) => write!(f, "digit in radix {radix}"),
+            Self::Number => f.write_str("number within range"),
+            Self::Escape => f.write_str("known escape sequence"),
+        }
+    }
+}
+
+/// Maximal amount of items stored inside an [`ExpectedSet`].
+///
+/// [`ExpectedSet`]: struct.ExpectedSet.html
+pub const EXPECTED_CAPACITY: usize = 4;
+
+/// Set of descriptions of what was expected, e.g. by each of the failed alternatives at the same position.
+/// ## Notes
+/// Items beyond [`EXPECTED_CAPACITY`] are dropped, which is indicated by the [`is_truncated`] method.
+/// The set is collected by alternatives matching trees and returned with their result by
+/// [`AlternativesMatch::finalize_merged`], while [`MatchFailed`] itself carries only a single item,
+/// so it stays small.
+///
+/// [`EXPECTED_CAPACITY`]: constant.EXPECTED_CAPACITY.html
+/// [`is_truncated`]: #method.is_truncated
+/// [`AlternativesMatch::finalize_merged`]: struct.AlternativesMatch.html#method.finalize_merged
+/// [`MatchFailed`]: struct.MatchFailed.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExpectedSet {
+    items: [Expected; EXPECTED_CAPACITY],
+    length: usize,
+    truncated: bool,
+}
+
+impl ExpectedSet {
+    /// Constructs a new instance holding a single item.
+    #[must_use]
+    pub const fn new(expected: Expected) -> Self {
+        Self {
+            items: [expected; EXPECTED_CAPACITY],
+            length: 1,
+            truncated: false,
+        }
+    }
+
+    /// Returns the stored items.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Expected] {
+        &self.items[..self.length]
+    }
+
+    /// Returns boolean indicating whether some items were dropped.
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Adds the passed item, unless it's already present.
+    /// `Expected::Unknown` is added only in place of an empty set and is replaced by the first known item.
+    pub fn insert(&mut self, expected: Expected) {
+        if self.as_slice().contains(&expected)
+            || (expected == Expected::Unknown && self.length != 0)
+        {
+            return;
+        }
+
+        if self.as_slice() == [Expected::Unknown] {
+            self.items[0] = expected;
+        } else if let Some(item) = self.items.get_mut(self.length) {
+            *item = expected;
+
+            self.length += 1;
+        } else {
+            self.truncated = true;
+        }
+    }
+
+    /// Adds all items of the passed set, unless they are already present.
+    pub fn extend(&mut self, other: &Self) {
+        for expected in other.as_slice() {
+            self.insert(*expected);
+        }
+
+        self.truncated |= other.truncated;
+    }
+
+    /// Updates the set after the `current` failure was merged with the `other` one.
+    /// The set starts over when the other failure reached further and is left as is when it reached less far.
+    pub(crate) fn merge(&mut self, current: &MatchFailed, other: &MatchFailed) {
+        match current.reach(other) {
+            Ordering::Greater => {}
+            Ordering::Less => *self = Self::new(other.expected),
+            Ordering::Equal => self.insert(other.expected),
+        }
+    }
+}
+
+impl Default for ExpectedSet {
+    fn default() -> Self {
+        Self::new(Expected::Unknown)
+    }
+}
+
+impl Display for ExpectedSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.as_slice() {
+            [expected] if !self.truncated => Display::fmt(expected, f),
+            items => {
+                f.write_str("one of ")?;
+
+                for (index, expected) in items.iter().enumerate() {
+                    if index != 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    Display::fmt(expected, f)?;
+                }
+
+                if self.truncated {
+                    f.write_str(", ...")
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+/// Describes what was found in place of the expected pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Found {
+    /// Indicates that no information is available.
+    #[default]
+    Unknown,
+    /// Indicates that the input ended before the pattern could be matched.
+    EndOfInput,
+    /// Indicates a piece of text that differs from the expected one.
+    Text(Snippet),
+    /// Indicates elements that differ from the expected ones, starting at the given offset from the failure's position.
+    Mismatch(usize),
+    /// Indicates that only the given amount of elements (characters by default in case of `&str`) matched a
+    /// "dynamic" pattern.
+    Count(usize),
+    /// Indicates that matching was stopped after reaching the given depth of nested recursive rules.
+    RecursionLimit(usize),
+    /// Indicates that a pattern matched where it wasn't expected to, as asserted by a negative lookahead.
+    Unexpected,
+    /// Indicates a number that doesn't fit within its type.
+    Overflow,
+    /// Indicates a variable-length number encoded with more bytes than needed.
+    Overlong,
+    /// Indicates the given control character, appearing unescaped within a string literal that forbids it.
+    Control(u8),
+}
+
+impl Display for Found {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown => f.write_str("unknown"),
+            Self::EndOfInput => f.write_str("end of input"),
+            Self::Text(text) => write!(f, "`{text}`"),
+            Self::Mismatch(offset) => write!(f, "mismatching element at offset {offset}"),
+            Self::Count(count) => write!(f, "{count} element(s)"),
+            Self::RecursionLimit(limit) => write!(f, "recursion limit of {limit}"),
+            Self::Unexpected => f.write_str("unexpected match"),
+            Self::Overflow => f.write_str("number out of range"),
+            Self::Overlong => f.write_str("overlong encoding"),
+            Self::Control(byte) => write!(f, "unescaped control character U+{byte:04X}"),
+        }
+    }
+}
+
+/// Describes how much more input is needed in order to continue matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Needed {
+    /// Indicates that the needed amount is not known.
+    Unknown,
+    /// Indicates that at least the given amount of elements (bytes in case of `&str`) is needed.
+    Size(usize),
+}
+
+impl Needed {
+    /// Constructs a new instance, falling back to `Unknown` when the passed amount is zero.
+    #[must_use]
+    pub const fn new(size: usize) -> Self {
+        if size == 0 {
+            Self::Unknown
+        } else {
+            Self::Size(size)
+        }
+    }
+}
+
+impl Display for Needed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown => f.write_str("more input needed"),
+            Self::Size(size) => write!(f, "{size} more element(s) needed"),
+        }
+    }
+}
+
+/// Represents failed pattern matching result.
+/// ## Incomplete input
+/// When matching partial input (see [`Partial`]), reaching the end of the input is not a failure in itself.
+/// In that case the result is marked as incomplete, carrying how much more input is [`needed`].
+/// Combinators forward incomplete results instead of trying other branches, so the matching can be
+/// restarted once more input arrives.
+/// ## Position
+/// Inputs are matched by splitting them into smaller and smaller parts, so the failure cannot know where the
+/// original input has started.
+/// Instead it records how many elements (bytes in case of `&str`) were remaining from the failure's position
+/// to the end of the input. The [`position`] method turns that into an offset within the original input.
+///
+/// [`Partial`]: ../input/struct.Partial.html
+/// [`needed`]: #method.needed
+/// [`position`]: #method.position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MatchFailed {
+    remaining: Option<usize>,
+    expected: Expected,
+    found: Found,
+    needed: Option<Needed>,
+}
+
+impl MatchFailed {
+    /// Constructs a new instance.
+    #[must_use]
+    pub const fn new(remaining: usize, expected: Expected, found: Found) -> Self {
+        Self {
+            remaining: Some(remaining),
+            expected,
+            found,
+            needed: None,
+        }
+    }
+
+    /// Constructs a new instance that carries no information about the failure.
+    pub(crate) const fn unknown() -> Self {
+        Self {
+            remaining: None,
+            expected: Expected::Unknown,
+            found: Found::Unknown,
+            needed: None,
+        }
+    }
+
+    /// Marks the failure as caused by incomplete input.
+    #[must_use]
+    pub const fn incomplete(self, needed: Needed) -> Self {
+        Self {
+            needed: Some(needed),
+            ..self
+        }
+    }
+
+    /// Returns boolean indicating whether the failure was caused by incomplete input.
+    #[must_use]
+    pub const fn is_incomplete(&self) -> bool {
+        self.needed.is_some()
+    }
+
+    /// Returns boolean indicating whether combinators should forward the failure instead of trying other branches.
+    /// This is the case for failures caused by incomplete input or by reaching a recursion limit.
+    #[must_use]
+    pub const fn is_unrecoverable(&self) -> bool {
+        self.is_incomplete() || matches!(self.found, Found::RecursionLimit(_))
+    }
+
+    /// Returns how much more input is needed, if the failure was caused by incomplete input.
+    #[must_use]
+    pub const fn needed(&self) -> Option<Needed> {
+        self.needed
+    }
+
+    /// Replaces the description of what was expected.
+    pub(crate) fn with_expected(mut self, expected: Expected) -> Self {
+        self.expected = expected;
+        self
+    }
+
+    /// Replaces the description of what was found.
+    #[cfg(feature = "std")]
+    pub(crate) fn with_found(mut self, found: Found) -> Self {
+        self.found = found;
+        self
+    }
+
+    /// Returns the amount of elements from the failure's position to the end of the input.
+    /// Returns `None` when the position is unknown.
+    #[must_use]
+    pub const fn remaining(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Returns the offset of the failure within the original input, given the original input's length.
+    /// Returns `None` when the position is unknown or the failure doesn't belong to the original input.
+    #[must_use]
+    pub fn position(&self, input_length: usize) -> Option<usize> {
+        self.remaining
+            .and_then(|remaining| input_length.checked_sub(remaining))
+    }
+
+    /// Returns description of what was expected.
+    /// After merging failures of alternatives, this is the first known item at the failure's position.
+    #[must_use]
+    pub const fn expected(&self) -> &Expected {
+        &self.expected
+    }
+
+    /// Merges failures of alternative matches, keeping the one that reached further into the input.
+    /// When both failed at the same position, the first known descriptions are kept.
+    /// Failures with an unknown position are considered to have reached the least far.
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        match self.reach(&other) {
+            Ordering::Greater => self,
+            Ordering::Less => other,
+            Ordering::Equal => {
+                let mut merged: Self = self;
+
+                if merged.expected == Expected::Unknown {
+                    merged.expected = other.expected;
+                }
+
+                if merged.found == Found::Unknown {
+                    merged.found = other.found;
+                }
+
+                merged
+            }
+        }
+    }
+
+    /// Compares how far into the input the failures have reached.
+    fn reach(&self, other: &Self) -> Ordering {
+        match (self.remaining, other.remaining) {
+            (Some(remaining), Some(other_remaining)) => other_remaining.cmp(&remaining),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    /// Returns description of what was found.
+    #[must_use]
+    pub const fn found(&self) -> &Found {
+        &self.found
+    }
+}
+
+impl Default for MatchFailed {
+    fn default() -> Self {
+        Self::unknown()
+    }
+}
+
+impl Display for MatchFailed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(needed) = &self.needed {
+            write!(f, "incomplete input, {needed}")
+        } else if let (Expected::Unknown, Found::Unknown) = (&self.expected, &self.found) {
+            f.write_str("matching failed")
+        } else {
+            write!(f, "expected {}, found {}", self.expected, self.found)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MatchFailed {}
+
+/// Represents failure of all branches of an alternatives matching tree, together with everything that was
+/// expected by the branches which reached the furthest, as returned by [`AlternativesMatch::finalize_merged`].
+/// ## Notes
+/// Displayed as e.g. "expected one of `!`, `@`, `#`, found `$`", while the merged [`MatchFailed`] alone is
+/// displayed with only the first of them.
+///
+/// [`AlternativesMatch::finalize_merged`]: struct.AlternativesMatch.html#method.finalize_merged
+/// [`MatchFailed`]: struct.MatchFailed.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AlternativesFailed {
+    failure: MatchFailed,
+    expected: ExpectedSet,
+}
+
+impl AlternativesFailed {
+    /// Constructs a new instance.
+    /// Unrecoverable failures are forwarded by alternatives as they are, so they keep only their own description.
+    #[must_use]
+    pub const fn new(failure: MatchFailed, expected: ExpectedSet) -> Self {
+        Self {
+            failure,
+            expected: if failure.is_unrecoverable() {
+                ExpectedSet::new(failure.expected)
+            } else {
+                expected
+            },
+        }
+    }
+
+    /// Returns the merged failure.
+    #[must_use]
+    pub const fn failure(&self) -> &MatchFailed {
+        &self.failure
+    }
+
+    /// Returns descriptions of everything that was expected at the failure's position.
+    #[must_use]
+    pub const fn expected_set(&self) -> &ExpectedSet {
+        &self.expected
+    }
+}
+
+impl From<AlternativesFailed> for MatchFailed {
+    fn from(error: AlternativesFailed) -> Self {
+        error.failure
+    }
+}
+
+impl Display for AlternativesFailed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if !self.expected.is_truncated() && self.expected.as_slice() == [self.failure.expected] {
+            Display::fmt(&self.failure, f)
+        } else {
+            write!(
+                f,
+                "expected {}, found {}",
+                self.expected, self.failure.found
+            )
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AlternativesFailed {}
@@ -0,0 +1,139 @@
+use crate::{
+    result::{Match, MatchFailed},
+    traits::MatchFail,
+};
+
+mod private {
+    pub trait Append<X> {
+        type Output;
+
+        fn append(self, element: X) -> Self::Output;
+    }
+
+    macro_rules! impl_append {
+        ($($t: ident $v: ident),*) => {
+            impl<$($t,)* X> Append<X> for ($($t,)*) {
+                type Output = ($($t,)* X,);
+
+                fn append(self, element: X) -> Self::Output {
+                    let ($($v,)*) = self;
+
+                    ($($v,)* element,)
+                }
+            }
+        };
+    }
+
+    impl_append!();
+    impl_append!(A a);
+    impl_append!(A a, B b);
+    impl_append!(A a, B b, C c);
+    impl_append!(A a, B b, C c, D d);
+    impl_append!(A a, B b, C c, D d, E e);
+    impl_append!(A a, B b, C c, D d, E e, F f);
+    impl_append!(A a, B b, C c, D d, E e, F f, G g);
+    impl_append!(A a, B b, C c, D d, E e, F f, G g, H h);
+    impl_append!(A a, B b, C c, D d, E e, F f, G g, H h, I i);
+    impl_append!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j);
+    impl_append!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k);
+}
+
+use private::Append;
+
+/// Represents a sequence of matches, accumulating the kept "matched" parts in a tuple.
+/// ## Notes
+/// Up to twelve "matched" parts can be kept.
+#[must_use]
+pub struct SequenceMatch<T, U> {
+    matched: Option<T>,
+    rest: Result<U, MatchFailed>,
+}
+
+impl<U> SequenceMatch<(), U> {
+    /// Creates new instance.
+    pub const fn new(rest: U) -> Self {
+        Self {
+            matched: Some(()),
+            rest: Ok(rest),
+        }
+    }
+}
+
+impl<T, U> SequenceMatch<T, U> {
+    /// Returns boolean indicating whether the pattern was matched.
+    /// This returns true when the pattern didn't match.
+    pub const fn is_failed(&self) -> bool {
+        self.rest.is_err()
+    }
+
+    /// Returns boolean indicating whether matching has stopped because of incomplete input.
+    /// Incomplete matching results are considered failed as well.
+    pub const fn is_incomplete(&self) -> bool {
+        matches!(&self.rest, Err(error) if error.is_incomplete())
+    }
+
+    /// Executes the matching function once, keeping the "matched" part as the next element of the tuple.
+    /// ## Notes
+    /// If the result doesn't have "matched" part, the sequence is considered failed.
+    pub fn then<F, R, M>(self, f: F) -> SequenceMatch<T::Output, U>
+    where
+        T: Append<M>,
+        F: FnOnce(U) -> R,
+        R: Into<Match<M, U>>,
+    {
+        match (self.matched, self.rest) {
+            (Some(kept), Ok(rest)) => {
+                let result: Match<M, U> = f(rest).into();
+
+                match (result.matched, result.rest) {
+                    (Some(matched), Ok(rest)) => SequenceMatch {
+                        matched: Some(kept.append(matched)),
+                        rest: Ok(rest),
+                    },
+                    (None, Ok(_)) => SequenceMatch::failed(),
+                    (_, Err(error)) => SequenceMatch::failed_with(error),
+                }
+            }
+            (None, Ok(_)) => SequenceMatch::failed(),
+            (_, Err(error)) => SequenceMatch::failed_with(error),
+        }
+    }
+
+    /// Executes the matching function once, discarding the "matched" part while keeping only the "rest" part.
+    pub fn skip<F, R, M>(mut self, f: F) -> Self
+    where
+        F: FnOnce(U) -> R,
+        R: Into<Match<M, U>>,
+    {
+        match self.rest {
+            Ok(rest) => {
+                self.rest = f(rest).into().rest;
+
+                self
+            }
+            Err(error) => Self::failed_with(error),
+        }
+    }
+
+    /// Calls to this method indicate that the sequence is completed and the final result should be returned.
+    /// The tuple of kept "matched" parts becomes the "matched" part.
+    pub fn finalize(self) -> Match<T, U> {
+        match self.rest {
+            Ok(rest) => Match::new(self.matched, rest),
+            Err(error) => Match::failed_with(error),
+        }
+    }
+}
+
+impl<T, U> MatchFail for SequenceMatch<T, U> {
+    fn failed() -> Self {
+        Self::failed_with(MatchFailed::default())
+    }
+
+    fn failed_with(error: MatchFailed) -> Self {
+        Self {
+            matched: None,
+            rest: Err(error),
+        }
+    }
+}
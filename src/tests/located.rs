@@ -0,0 +1,74 @@
+use crate::{
+    input::{Located, Location},
+    result::MatchFailed,
+    traits::*,
+};
+
+fn located_test(data: &[u8]) -> (Located<&[u8]>, Located<&[u8]>) {
+    let (matched, rest) = Located::new(data)
+        .match_static(b"#")
+        .match_with(|byte: u8| byte == b'\n')
+        .match_exact_with(2, |byte: u8| byte.is_ascii_digit())
+        .unwrap();
+
+    (matched.unwrap(), rest)
+}
+
+#[test]
+fn located() {
+    let (matched, rest) = located_test(b"#\n\n12\n34");
+
+    assert_eq!(matched.fragment(), &&b"12"[..]);
+    assert_eq!(matched.location(), Location::new(3, 3, 1));
+    assert_eq!(matched.end(), Location::new(5, 3, 3));
+    assert_eq!(rest.location(), Location::new(5, 3, 3));
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn located_panic() {
+    located_test(b"#\n\nAB\n34");
+}
+
+fn located_str_test(data: &str) -> (Located<&str>, Located<&str>) {
+    let (matched, rest) = Located::new(data)
+        .match_static("#")
+        .match_with(char::is_whitespace)
+        .match_min_with(2, char::is_alphabetic)
+        .unwrap();
+
+    (matched.unwrap(), rest)
+}
+
+#[test]
+fn located_str() {
+    let (matched, rest) = located_str_test("#\n  абв\n");
+
+    assert_eq!(matched.fragment(), &"абв");
+    assert_eq!(matched.location(), Location::new(4, 2, 3));
+    assert_eq!(matched.end(), Location::new(10, 2, 6));
+    assert_eq!(rest.location(), Location::new(10, 2, 6));
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn located_str_panic() {
+    located_str_test("#\n  1\n");
+}
+
+#[test]
+fn located_failure() {
+    let data: Located<&str> = Located::new("#\n  x = 1\n  y = ?\n");
+
+    let error: MatchFailed = data
+        .match_static("#")
+        .match_with(char::is_whitespace)
+        .match_static("x = 1")
+        .match_with(char::is_whitespace)
+        .match_static("y = ")
+        .match_min_with(1, |c: char| c.is_ascii_digit())
+        .take()
+        .unwrap_err();
+
+    assert_eq!(data.locate(&error), Some(Location::new(16, 3, 7)));
+}
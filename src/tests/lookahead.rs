@@ -0,0 +1,80 @@
+use crate::{
+    result::{Expected, Found, Match, MatchFailed},
+    traits::*,
+};
+
+fn keyword<'a>(data: &'a str, keyword: &str) -> Match<&'a str, &'a str> {
+    data.match_static(keyword)
+        .not(|_, rest| rest.match_min_with(1, |c: char| c == '_' || c.is_alphanumeric()))
+}
+
+#[test]
+fn lookahead_not() {
+    assert_eq!(keyword("if x", "if").unwrap(), (Some("if"), " x"));
+    assert_eq!(keyword("if", "if").unwrap(), (Some("if"), ""));
+    assert_eq!(
+        keyword("iffy", "if").error().unwrap().found(),
+        &Found::Unexpected
+    );
+}
+
+#[test]
+fn lookahead_not_position() {
+    assert_eq!(
+        keyword("iffy x", "if").error(),
+        Some(&MatchFailed::new(4, Expected::Unknown, Found::Unexpected))
+    );
+    assert_eq!(
+        "-1 x"
+            .match_static_mapped("-", true)
+            .not(|_, rest| rest.match_min_with(1, char::is_numeric))
+            .error(),
+        Some(&MatchFailed::new(3, Expected::Unknown, Found::Unexpected))
+    );
+}
+
+fn comment(data: &[u8]) -> Match<&[u8], &[u8]> {
+    data.match_static(b"/*").recognize(|mut rest: &[u8]| loop {
+        let step: Match<&[u8], &[u8]> = rest
+            .into_match::<&[u8]>()
+            .not(|_, rest| rest.match_static(b"*/"))
+            .match_exact_with(1, |_: u8| true);
+
+        match step.take() {
+            Ok((_, next)) => rest = next,
+            Err(_) => {
+                break rest
+                    .into_match::<&[u8]>()
+                    .peek(|_, rest| rest.match_static(b"*/"))
+            }
+        }
+    })
+}
+
+#[test]
+fn lookahead_peek() {
+    assert_eq!(
+        comment(b"/* a * b */ c").unwrap(),
+        (Some(&b" a * b "[..]), &b"*/ c"[..])
+    );
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn lookahead_peek_panic() {
+    let _ = comment(b"/* a * b").unwrap();
+}
+
+#[test]
+fn lookahead_mapped() {
+    let result = "-1"
+        .match_static_mapped("-", true)
+        .peek(|_, rest| rest.match_min_with(1, char::is_numeric));
+
+    assert_eq!(result.unwrap(), (Some(("-", true)), "1"));
+
+    assert!("-1"
+        .match_static_mapped("-", true)
+        .not(|_, rest| rest.match_min_with(1, char::is_numeric))
+        .is_failed());
+}
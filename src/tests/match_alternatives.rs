@@ -71,3 +71,85 @@ fn match_alternatives_str() {
 fn match_alternatives_str_panic() {
     match_alternatives_str_test("#000000");
 }
+
+fn operator(data: &str) -> Option<&str> {
+    data.alternatives::<&str, &str>()
+        .longest()
+        .add_path(|rest| rest.match_static("="))
+        .add_path(|rest| rest.match_static("=="))
+        .add_path(|rest| rest.match_static("!="))
+        .finalize()
+        .unwrap()
+        .0
+}
+
+#[test]
+fn match_alternatives_longest() {
+    assert_eq!(operator("== 1"), Some("=="));
+    assert_eq!(operator("= 1"), Some("="));
+    assert_eq!(operator("!= 1"), Some("!="));
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn match_alternatives_longest_panic() {
+    let _ = operator("< 1");
+}
+
+#[test]
+fn match_alternatives_longest_tie() {
+    let (matched, rest) = b"in x"
+        .alternatives::<&[u8], &[u8]>()
+        .longest()
+        .add_path(|rest| rest.match_static(b"in").match_with(|_: u8| false))
+        .add_path(|rest| rest.match_min_with(1, |byte: u8| byte.is_ascii_alphabetic()))
+        .finalize()
+        .unwrap();
+
+    assert_eq!(matched, Some(&b""[..]));
+    assert_eq!(rest, b" x");
+}
+
+fn keyword(data: &str) -> (Option<&str>, Option<usize>) {
+    let (result, index) = data
+        .alternatives::<&str, &str>()
+        .add_path(|rest| rest.match_static("if"))
+        .add_path(|rest| rest.match_static("else"))
+        .add_path(|rest| rest.match_static("while"))
+        .finalize_indexed();
+
+    (result.matched().copied(), index)
+}
+
+#[test]
+fn match_alternatives_indexed() {
+    assert_eq!(keyword("if x"), (Some("if"), Some(0)));
+    assert_eq!(keyword("while x"), (Some("while"), Some(2)));
+    assert_eq!(keyword("for x"), (None, None));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    Assign,
+    Equal,
+}
+
+fn tagged_operator(data: &str) -> (Option<&str>, Option<Operator>) {
+    let (result, tag) = data
+        .tagged_alternatives::<&str, &str, _>()
+        .longest()
+        .add_path(|rest| rest.match_static("!="))
+        .add_tagged_path(Operator::Assign, |rest| rest.match_static("="))
+        .add_tagged_path(Operator::Equal, |rest| rest.match_static("=="))
+        .finalize_tagged();
+
+    (result.matched().copied(), tag)
+}
+
+#[test]
+fn match_alternatives_tagged() {
+    assert_eq!(tagged_operator("== 1"), (Some("=="), Some(Operator::Equal)));
+    assert_eq!(tagged_operator("= 1"), (Some("="), Some(Operator::Assign)));
+    assert_eq!(tagged_operator("!= 1"), (Some("!="), None));
+    assert_eq!(tagged_operator("< 1"), (None, None));
+}
@@ -0,0 +1,351 @@
+use core::convert::TryFrom;
+
+use crate::{
+    input::Partial,
+    parser::{on_input, Binary, Endian, Varint},
+    result::{Expected, Found, MappedMatch, MatchFailed, Needed},
+    traits::*,
+};
+
+#[test]
+fn match_binary() {
+    let data: &[u8] = &[0x12, 0x34, 0x56, 0x78, 0xFF];
+
+    assert_eq!(
+        data.match_binary(Binary::<u32>::big()).unwrap(),
+        (Some((&data[..4], 0x1234_5678)), &data[4..])
+    );
+    assert_eq!(
+        data.match_binary(Binary::<u32>::little()).unwrap().0,
+        Some((&data[..4], 0x7856_3412))
+    );
+    assert_eq!(
+        data.match_binary(Binary::<u16>::big())
+            .match_binary(Binary::<i16>::little())
+            .unwrap(),
+        (Some((&data[2..4], 0x7856)), &data[4..])
+    );
+    assert_eq!(
+        data[4..].match_binary(Binary::<i8>::big()).unwrap().0,
+        Some((&data[4..], -1))
+    );
+
+    let wide: [u8; 16] = 0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10_u128.to_le_bytes();
+
+    assert_eq!(
+        wide[..]
+            .match_binary(Binary::<u128>::little())
+            .unwrap()
+            .0
+            .map(|(_, value)| value),
+        Some(0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10)
+    );
+    assert_eq!(
+        wide[..]
+            .match_binary(Binary::<i128>::big())
+            .unwrap()
+            .0
+            .map(|(_, value)| value),
+        Some(i128::from_be_bytes(wide))
+    );
+}
+
+#[test]
+fn match_binary_float() {
+    let value = |data: &[u8], format: Binary<f64>| {
+        data.match_binary(format)
+            .unwrap()
+            .0
+            .map(|(_, value)| value.to_bits())
+    };
+
+    assert_eq!(
+        value(&1.5_f64.to_be_bytes(), Binary::big()),
+        Some(1.5_f64.to_bits())
+    );
+    assert_eq!(
+        value(&(-0.25_f64).to_le_bytes(), Binary::little()),
+        Some((-0.25_f64).to_bits())
+    );
+
+    let (matched, rest) = [0x40, 0x49, 0x0F, 0xDB, 0x00]
+        .as_ref()
+        .match_binary(Binary::<f32>::big())
+        .unwrap();
+
+    assert_eq!(
+        matched.map(|(_, value)| value.to_bits()),
+        Some(core::f32::consts::PI.to_bits())
+    );
+    assert_eq!(rest, [0x00]);
+}
+
+#[test]
+fn match_binary_endian() {
+    // The byte order is chosen at runtime, as by the byte order mark of TIFF files.
+    for (data, expected) in [
+        (&b"II\x2A\x00"[..], Endian::Little),
+        (b"MM\x00\x2A", Endian::Big),
+    ] {
+        let mut endian: Endian = Endian::NATIVE;
+
+        let (value, rest) = data
+            .mapped_alternatives()
+            .add_path(|input: &[u8]| input.match_static(b"II").map(Endian::Little))
+            .add_path(|input: &[u8]| input.match_static(b"MM").map(Endian::Big))
+            .finalize()
+            .execute(|matched, _| endian = matched.unwrap().1)
+            .unmap(|_| ())
+            .unwrap();
+
+        assert_eq!((value, rest), (Some(&data[..2]), &data[2..]));
+        assert_eq!(endian, expected);
+        assert_eq!(
+            rest.match_binary(Binary::<u16>::new(endian)).unwrap().0,
+            Some((&data[2..], 42))
+        );
+    }
+}
+
+#[test]
+fn match_binary_failure() {
+    let data: &[u8] = &[0x01, 0x02, 0x03];
+
+    assert_eq!(
+        data.match_binary(Binary::<u32>::big()).error(),
+        Some(&MatchFailed::new(
+            3,
+            Expected::Elements(4),
+            Found::EndOfInput
+        ))
+    );
+    assert_eq!(
+        data.match_binary(Binary::<u16>::big())
+            .match_binary(Binary::<u16>::big())
+            .error(),
+        Some(&MatchFailed::new(
+            1,
+            Expected::Elements(2),
+            Found::EndOfInput
+        ))
+    );
+}
+
+#[test]
+fn match_binary_partial() {
+    let data: &[u8] = &[0x01, 0x02, 0x03];
+
+    let (value, rest) = Partial::new(data)
+        .match_binary(Binary::<u16>::big())
+        .unwrap();
+
+    assert_eq!(value.map(|(_, value)| value), Some(0x0102));
+    assert_eq!(rest.fragment(), &&data[2..]);
+
+    let error: MatchFailed = Partial::new(data)
+        .match_binary(Binary::<u64>::little())
+        .take()
+        .unwrap_err();
+
+    assert!(error.is_incomplete());
+    assert_eq!(error.needed(), Some(Needed::Size(5)));
+
+    assert!(!Partial::last(data)
+        .match_binary(Binary::<u64>::little())
+        .take()
+        .unwrap_err()
+        .is_incomplete());
+}
+
+#[test]
+fn match_binary_parser() {
+    let result: MappedMatch<&[u8], &[u8], u16> = [0xAB, 0xCD]
+        .as_ref()
+        .mapped_alternatives()
+        .add_path(on_input(Binary::little()))
+        .finalize();
+
+    assert_eq!(result.unwrap().0, Some((&[0xAB, 0xCD][..], 0xCDAB)));
+}
+
+/// Encodes the value as signed LEB128, or as unsigned LEB128 of its two's complement bits if requested.
+fn leb128(mut value: i128, unsigned: bool, output: &mut [u8; 20]) -> &[u8] {
+    let mut length: usize = 0;
+
+    loop {
+        let byte: u8 = u8::try_from(value & 0x7F).unwrap();
+
+        value >>= 7;
+
+        let done: bool = if unsigned {
+            value == 0
+        } else {
+            (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0)
+        };
+
+        output[length] = if done { byte } else { byte | 0x80 };
+        length += 1;
+
+        if done {
+            return &output[..length];
+        }
+    }
+}
+
+#[test]
+fn match_binary_varint() {
+    let data: &[u8] = &[0xE5, 0x8E, 0x26, 0xFF];
+
+    assert_eq!(
+        data.match_binary(Varint::<u32>::leb128()).unwrap(),
+        (Some((&data[..3], 624_485)), &data[3..])
+    );
+    assert_eq!(
+        [0xC0, 0xBB, 0x78]
+            .as_ref()
+            .match_binary(Varint::<i32>::signed_leb128())
+            .unwrap()
+            .0
+            .map(|(_, value)| value),
+        Some(-123_456)
+    );
+
+    for (data, expected) in [
+        (&[0x00][..], 0),
+        (&[0x01], -1),
+        (&[0x02], 1),
+        (&[0x03], -2),
+        (&[0xFE, 0xFF, 0xFF, 0xFF, 0x0F], i32::MAX),
+        (&[0xFF, 0xFF, 0xFF, 0xFF, 0x0F], i32::MIN),
+    ] {
+        assert_eq!(
+            data.match_binary(Varint::<i32>::zigzag()).unwrap(),
+            (Some((data, expected)), &[][..])
+        );
+    }
+
+    // A field of Protocol Buffers: the key (field number 1, wire type 0) followed by the value.
+    let (value, _) = [0x08, 0x96, 0x01]
+        .as_ref()
+        .match_binary(Varint::<u32>::leb128())
+        .assert(|matched, _| matched.map(|(_, key)| key) == Some(0x08))
+        .match_binary(Varint::<u64>::leb128())
+        .unwrap();
+
+    assert_eq!(value.map(|(_, value)| value), Some(150));
+}
+
+#[test]
+fn match_binary_varint_round_trip() {
+    let mut output: [u8; 20] = [0; 20];
+
+    for value in [0, 1, 63, 64, 127, 128, 8191, 8192, u64::MAX / 3, u64::MAX] {
+        let data: &[u8] = leb128(i128::from(value), true, &mut output);
+
+        assert_eq!(
+            data.match_binary(Varint::<u64>::leb128()).unwrap(),
+            (Some((data, value)), &[][..]),
+            "value {value}"
+        );
+    }
+
+    for value in [
+        0,
+        -1,
+        63,
+        -64,
+        64,
+        -65,
+        i128::from(i64::MIN),
+        i128::MAX,
+        i128::MIN,
+    ] {
+        let data: &[u8] = leb128(value, false, &mut output);
+
+        assert_eq!(
+            data.match_binary(Varint::<i128>::signed_leb128()).unwrap(),
+            (Some((data, value)), &[][..]),
+            "value {value}"
+        );
+    }
+
+    for value in i8::MIN..=i8::MAX {
+        let data: &[u8] = leb128(i128::from(value), false, &mut output);
+
+        assert_eq!(
+            data.match_binary(Varint::<i8>::signed_leb128()).unwrap(),
+            (Some((data, value)), &[][..]),
+            "value {value}"
+        );
+    }
+
+    let data: &[u8] = leb128(i128::MAX, true, &mut output);
+
+    assert_eq!(
+        data.match_binary(Varint::<u128>::leb128()).unwrap().0,
+        Some((data, u128::try_from(i128::MAX).unwrap()))
+    );
+}
+
+#[test]
+fn match_binary_varint_failure() {
+    for (data, format, found) in [
+        (&[0xFF, 0x01][..], Varint::<u8>::leb128(), None),
+        (&[0x80, 0x02], Varint::leb128(), Some(Found::Overflow)),
+        (&[0xFF, 0x81, 0x00], Varint::leb128(), Some(Found::Overflow)),
+        (&[0x80, 0x00], Varint::leb128(), Some(Found::Overlong)),
+        (&[0x80, 0x00], Varint::leb128().allow_overlong(), None),
+        (&[0x80, 0x80, 0x00], Varint::leb128(), Some(Found::Overflow)),
+    ] {
+        assert_eq!(
+            data.match_binary(format).error(),
+            found
+                .map(|found| MatchFailed::new(data.len(), Expected::Number, found))
+                .as_ref(),
+            "decoding {data:?}"
+        );
+    }
+
+    for (data, found) in [
+        (&[0xFF, 0xFF, 0xFF, 0xFF, 0x1F][..], Found::Overflow),
+        (&[0xFF, 0xFF, 0xFF, 0xFF, 0x8F, 0x00], Found::Overflow),
+        (&[0x81, 0x00], Found::Overlong),
+    ] {
+        assert_eq!(
+            data.match_binary(Varint::<u32>::zigzag()).error(),
+            Some(&MatchFailed::new(data.len(), Expected::Number, found)),
+            "decoding {data:?}"
+        );
+    }
+
+    for (data, found) in [
+        (&[0xFF, 0x7F][..], Found::Overlong),
+        (&[0x80, 0x40], Found::Overflow),
+        (&[0xFF, 0x01], Found::Overflow),
+    ] {
+        assert_eq!(
+            data.match_binary(Varint::<i8>::signed_leb128()).error(),
+            Some(&MatchFailed::new(data.len(), Expected::Number, found)),
+            "decoding {data:?}"
+        );
+    }
+
+    assert_eq!(
+        [0x80, 0x80]
+            .as_ref()
+            .match_binary(Varint::<u64>::leb128())
+            .error(),
+        Some(&MatchFailed::new(
+            2,
+            Expected::Elements(3),
+            Found::EndOfInput
+        ))
+    );
+
+    let error: MatchFailed = Partial::new(&[0x80, 0x80][..])
+        .match_binary(Varint::<u64>::leb128())
+        .take()
+        .unwrap_err();
+
+    assert_eq!(error.needed(), Some(Needed::Size(1)));
+}
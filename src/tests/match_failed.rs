@@ -0,0 +1,188 @@
+use crate::{
+    result::{
+        AlternativesFailed, Expected, ExpectedSet, Found, Match, MatchFailed, Snippet,
+        EXPECTED_CAPACITY,
+    },
+    traits::*,
+};
+
+#[test]
+fn match_failed() {
+    let data: &[u8] = b"#12AB";
+
+    let error: MatchFailed = data
+        .match_static(b"#")
+        .match_static(b"12")
+        .match_static(b"AC")
+        .take()
+        .unwrap_err();
+
+    assert_eq!(error.position(data.len()), Some(3));
+    assert_eq!(error.expected(), &Expected::Elements(2));
+    assert_eq!(error.found(), &Found::Mismatch(1));
+}
+
+#[test]
+fn match_failed_end_of_input() {
+    let data: &[u8] = b"#1";
+
+    let error: MatchFailed = data
+        .match_static(b"#")
+        .match_static(b"12")
+        .take()
+        .unwrap_err();
+
+    assert_eq!(error.position(data.len()), Some(1));
+    assert_eq!(error.found(), &Found::EndOfInput);
+}
+
+#[test]
+fn match_failed_str() {
+    let data: &str = "#12AB";
+
+    let error: MatchFailed = data
+        .match_static("#")
+        .match_static("12")
+        .match_static("AC")
+        .take()
+        .unwrap_err();
+
+    assert_eq!(error.position(data.len()), Some(3));
+    assert_eq!(error.expected(), &Expected::Text(Snippet::new("AC")));
+    assert_eq!(error.found(), &Found::Text(Snippet::new("AB")));
+
+    #[cfg(feature = "std")]
+    assert_eq!(error.to_string(), "expected `AC`, found `AB`");
+}
+
+#[test]
+fn match_failed_in_range() {
+    let data: &str = "#1A";
+
+    let error: MatchFailed = data
+        .match_static("#")
+        .match_min_with(2, |c: char| c.is_ascii_digit())
+        .take()
+        .unwrap_err();
+
+    assert_eq!(error.position(data.len()), Some(1));
+    assert_eq!(error.expected(), &Expected::AtLeast(2));
+    assert_eq!(error.found(), &Found::Count(1));
+
+    let error: MatchFailed = data
+        .match_static("#")
+        .match_exact_with(2, |c: char| c.is_ascii_digit())
+        .take()
+        .unwrap_err();
+
+    assert_eq!(error.expected(), &Expected::Exactly(2));
+}
+
+#[test]
+fn match_failed_mapped() {
+    let data: &str = "#1A";
+
+    let error: MatchFailed = data
+        .match_static_mapped("#", ())
+        .match_static_mapped("2", ())
+        .take()
+        .unwrap_err();
+
+    assert_eq!(error.position(data.len()), Some(1));
+    assert_eq!(error.expected(), &Expected::Text(Snippet::new("2")));
+}
+
+#[test]
+fn match_failed_merged() {
+    let data: &str = "$123";
+
+    let alternatives = data
+        .alternatives::<&str, &str>()
+        .add_path(|rest| rest.match_static("!"))
+        .add_path(|rest| rest.match_static("@"))
+        .add_path(|rest| rest.match_static("#"));
+
+    assert_eq!(
+        alternatives.expected_set().as_slice(),
+        [
+            Expected::Text(Snippet::new("!")),
+            Expected::Text(Snippet::new("@")),
+            Expected::Text(Snippet::new("#")),
+        ]
+    );
+
+    let (result, expected): (Match<&str, &str>, ExpectedSet) = alternatives.finalize_merged();
+
+    let error: AlternativesFailed = AlternativesFailed::new(result.take().unwrap_err(), expected);
+
+    assert_eq!(error.expected_set().as_slice().len(), 3);
+    assert_eq!(error.failure().position(data.len()), Some(0));
+    assert_eq!(error.failure().found(), &Found::Text(Snippet::new("$")));
+
+    #[cfg(feature = "std")]
+    assert_eq!(
+        error.to_string(),
+        "expected one of `!`, `@`, `#`, found `$`"
+    );
+}
+
+#[test]
+fn match_failed_merged_furthest() {
+    let data: &str = "#12G";
+
+    let alternatives = data
+        .mapped_alternatives()
+        .add_path(|rest| rest.match_static_mapped("!", 0))
+        .add_path(|rest| {
+            rest.match_static("#")
+                .match_exact_with_mapped(3, |c: char| c.is_ascii_hexdigit(), 1)
+        })
+        .add_path(|rest| {
+            rest.match_static("#")
+                .match_static("12")
+                .match_static_mapped("H", 2)
+        })
+        .add_path(|rest| rest.match_static_mapped("?", 3));
+
+    assert_eq!(
+        alternatives.expected_set().as_slice(),
+        [Expected::Text(Snippet::new("H"))]
+    );
+
+    let (result, expected) = alternatives.finalize_merged();
+
+    let error: AlternativesFailed = AlternativesFailed::new(result.take().unwrap_err(), expected);
+
+    assert_eq!(error.failure().position(data.len()), Some(3));
+    assert_eq!(
+        error.failure().expected(),
+        &Expected::Text(Snippet::new("H"))
+    );
+
+    #[cfg(feature = "std")]
+    assert_eq!(error.to_string(), "expected `H`, found `G`");
+}
+
+#[test]
+fn match_failed_merged_truncated() {
+    let mut alternatives = b"".alternatives::<&[u8], &[u8]>();
+
+    for count in 1..=EXPECTED_CAPACITY + 1 {
+        alternatives = alternatives.add_path(|rest| rest.match_exact_with(count, |_: u8| true));
+    }
+
+    assert_eq!(
+        alternatives.expected_set().as_slice().len(),
+        EXPECTED_CAPACITY
+    );
+    assert!(alternatives.expected_set().is_truncated());
+    assert_eq!(
+        alternatives.finalize().error().unwrap().expected(),
+        &Expected::Exactly(1)
+    );
+}
+
+#[test]
+fn match_failed_size() {
+    assert!(core::mem::size_of::<MatchFailed>() <= 128);
+}
@@ -46,7 +46,7 @@ fn match_mapped_alternatives_str_test(data: &str) {
     let (matched, value_type): (&str, ValueType) = matched.unwrap();
 
     match value_type {
-        ValueType::HexColour(_) => {
+        ValueType::HexColour(HexColourType::SixDigit | HexColourType::ThreeDigit) => {
             let (_red, _green, _blue): (u8, u8, u8) = (
                 u8::from_str_radix(&matched[1..3], 16).unwrap(),
                 u8::from_str_radix(&matched[3..5], 16).unwrap(),
@@ -72,3 +72,25 @@ fn match_mapped_alternatives_str() {
 fn match_mapped_alternatives_str_panic() {
     match_mapped_alternatives_str_test("#123XYZ");
 }
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Keyword,
+    Identifier,
+}
+
+fn token(data: &str) -> Option<(&str, Token)> {
+    data.mapped_alternatives()
+        .longest()
+        .add_path(|rest| rest.match_static_mapped("in", Token::Keyword))
+        .add_path(|rest| rest.match_min_with_mapped(1, char::is_alphanumeric, Token::Identifier))
+        .finalize()
+        .unwrap()
+        .0
+}
+
+#[test]
+fn match_mapped_alternatives_longest() {
+    assert_eq!(token("in x"), Some(("in", Token::Keyword)));
+    assert_eq!(token("int x"), Some(("int", Token::Identifier)));
+}
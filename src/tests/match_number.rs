@@ -0,0 +1,352 @@
+use crate::{
+    parser::{on_input, Float, Integer},
+    result::{Expected, Found, MappedMatch, MatchFailed, Snippet},
+    traits::*,
+};
+
+#[test]
+fn match_number_integer() {
+    let (value, rest) = "1234 rest".match_number(Integer::<u32>::new()).unwrap();
+
+    assert_eq!(value, Some(("1234", 1234)));
+    assert_eq!(rest, " rest");
+
+    let (value, rest) = b"-128,".match_number(Integer::<i8>::new()).unwrap();
+
+    assert_eq!(value, Some((&b"-128"[..], -128)));
+    assert_eq!(rest, b",");
+
+    let (value, _) = "+42".match_number(Integer::<u8>::new()).unwrap();
+
+    assert_eq!(value, Some(("+42", 42)));
+}
+
+#[test]
+fn match_number_integer_radix() {
+    let hex: Integer<u8> = Integer::new().radix(16);
+
+    assert_eq!("Ff".match_number(hex).unwrap(), (Some(("Ff", 0xFF)), ""));
+    assert_eq!(
+        "100".match_number(hex).error(),
+        Some(&MatchFailed::new(3, Expected::Number, Found::Overflow))
+    );
+    assert_eq!(
+        "101".match_number(Integer::<u8>::new().radix(2)).unwrap(),
+        (Some(("101", 5)), "")
+    );
+
+    let prefixed: Integer<i64> = Integer::new().allow_prefixes().allow_separators();
+
+    for (data, expected) in [
+        ("0xFF_FF", 0xFFFF),
+        ("-0o17", -0o17),
+        ("0B1010_", 0b1010),
+        ("1_000_000", 1_000_000),
+        ("0", 0),
+    ] {
+        assert_eq!(
+            data.match_number(prefixed).unwrap(),
+            (Some((data, expected)), "")
+        );
+    }
+}
+
+#[test]
+fn match_number_integer_bounds() {
+    assert_eq!(
+        "127".match_number(Integer::<i8>::new()).unwrap().0,
+        Some(("127", 127))
+    );
+    assert_eq!(
+        "340282366920938463463374607431768211455"
+            .match_number(Integer::<u128>::new())
+            .unwrap()
+            .0,
+        Some(("340282366920938463463374607431768211455", u128::MAX))
+    );
+
+    for data in ["128", "-129", "256_0"] {
+        assert_eq!(
+            data.match_static("")
+                .match_number(Integer::<i8>::new().allow_separators())
+                .error(),
+            Some(&MatchFailed::new(
+                data.len(),
+                Expected::Number,
+                Found::Overflow
+            ))
+        );
+    }
+}
+
+#[test]
+fn match_number_integer_failure() {
+    assert_eq!(
+        "-1".match_number(Integer::<u8>::new()).error(),
+        Some(&MatchFailed::new(
+            2,
+            Expected::Digit(10),
+            Found::Text(Snippet::new("-"))
+        ))
+    );
+    assert_eq!(
+        "+".match_number(Integer::<u8>::new()).error(),
+        Some(&MatchFailed::new(0, Expected::Digit(10), Found::EndOfInput))
+    );
+    assert_eq!(
+        "+1".match_number(Integer::<u8>::new().forbid_sign())
+            .error(),
+        Some(&MatchFailed::new(
+            2,
+            Expected::Digit(10),
+            Found::Text(Snippet::new("+"))
+        ))
+    );
+    assert_eq!(
+        b"0xg"
+            .match_number(Integer::<u8>::new().allow_prefixes())
+            .error(),
+        Some(&MatchFailed::new(
+            1,
+            Expected::Digit(16),
+            Found::Mismatch(0)
+        ))
+    );
+    assert_eq!(
+        "_1".match_number(Integer::<u8>::new().allow_separators())
+            .error(),
+        Some(&MatchFailed::new(
+            2,
+            Expected::Digit(10),
+            Found::Text(Snippet::new("_"))
+        ))
+    );
+}
+
+#[test]
+fn match_number_integer_parser() {
+    let result: MappedMatch<&str, &str, u16> = "0x1F"
+        .mapped_alternatives()
+        .add_path(on_input(Integer::new().allow_prefixes()))
+        .finalize();
+
+    assert_eq!(result.unwrap(), (Some(("0x1F", 31)), ""));
+}
+
+#[test]
+#[should_panic(expected = "radix must be within 2..=36")]
+fn match_number_integer_radix_panic() {
+    let _: Integer<u8> = Integer::new().radix(37);
+}
+
+#[test]
+fn match_number_float() {
+    for (data, expected, rest) in [
+        ("1.5e3,", 1500.0, ","),
+        ("-0.25", -0.25, ""),
+        ("42 ", 42.0, " "),
+        ("2.5E-3", 0.0025, ""),
+        ("1e", 1.0, "e"),
+        ("1e+", 1.0, "e+"),
+    ] {
+        let (value, actual) = data.match_number(Float::<f64>::json()).unwrap();
+
+        assert_eq!(value.unwrap().1.to_bits(), f64::to_bits(expected));
+        assert_eq!(actual, rest);
+    }
+
+    let (value, rest) = b"0.1".match_number(Float::<f32>::json()).unwrap();
+
+    assert_eq!(value, Some((&b"0.1"[..], 0.1_f32)));
+    assert_eq!(rest, b"");
+}
+
+#[test]
+fn match_number_float_grammars() {
+    // Pairs of the matched part and the rest for the JSON, Rust-like and C-like grammars.
+    for (data, json, rust, c) in [
+        ("+1", None, Some(("+1", "")), Some(("+1", ""))),
+        (
+            "01.5",
+            Some(("0", "1.5")),
+            Some(("01.5", "")),
+            Some(("01.5", "")),
+        ),
+        (".5", None, None, Some((".5", ""))),
+        ("5.", Some(("5", ".")), Some(("5.", "")), Some(("5.", ""))),
+        (
+            "5..6",
+            Some(("5", "..6")),
+            Some(("5", "..6")),
+            Some(("5.", ".6")),
+        ),
+        (
+            "5.max",
+            Some(("5", ".max")),
+            Some(("5", ".max")),
+            Some(("5.", "max")),
+        ),
+        (
+            "5.e1",
+            Some(("5", ".e1")),
+            Some(("5", ".e1")),
+            Some(("5.e1", "")),
+        ),
+        (
+            "1_000.5",
+            Some(("1", "_000.5")),
+            Some(("1_000.5", "")),
+            Some(("1", "_000.5")),
+        ),
+        ("inf", None, Some(("inf", "")), Some(("inf", ""))),
+        (
+            "0x1p4",
+            Some(("0", "x1p4")),
+            Some(("0", "x1p4")),
+            Some(("0x1p4", "")),
+        ),
+    ] {
+        let actual = |format: Float<f64>| {
+            data.match_number(format)
+                .take()
+                .ok()
+                .map(|(matched, rest)| (matched.unwrap().0, rest))
+        };
+
+        assert_eq!(actual(Float::json()), json, "JSON grammar on {data:?}");
+        assert_eq!(actual(Float::rust()), rust, "Rust-like grammar on {data:?}");
+        assert_eq!(actual(Float::c()), c, "C-like grammar on {data:?}");
+    }
+}
+
+#[test]
+fn match_number_float_special() {
+    let format: Float<f64> = Float::rust();
+
+    assert_eq!(
+        "-Infinity".match_number(format).unwrap().0,
+        Some(("-Infinity", f64::NEG_INFINITY))
+    );
+    assert_eq!("INF!".match_number(format).unwrap().1, "!");
+    assert!("NaN".match_number(format).unwrap().0.unwrap().1.is_nan());
+    assert_eq!(
+        "nan".match_number(Float::<f64>::json()).error(),
+        Some(&MatchFailed::new(
+            3,
+            Expected::Digit(10),
+            Found::Text(Snippet::new("n"))
+        ))
+    );
+    assert_eq!(
+        "-".match_number(Float::<f64>::json()).error(),
+        Some(&MatchFailed::new(0, Expected::Digit(10), Found::EndOfInput))
+    );
+}
+
+#[test]
+fn match_number_float_special_boundary() {
+    let format: Float<f64> = Float::rust();
+
+    assert_eq!(
+        "information".match_number(format).error(),
+        Some(&MatchFailed::new(
+            11,
+            Expected::Digit(10),
+            Found::Text(Snippet::new("i"))
+        ))
+    );
+    assert_eq!(
+        "nancy".match_number(format).error(),
+        Some(&MatchFailed::new(
+            5,
+            Expected::Digit(10),
+            Found::Text(Snippet::new("n"))
+        ))
+    );
+    assert!("inf_".match_number(format).error().is_some());
+    assert!("Infinity2".match_number(format).error().is_some());
+    assert_eq!("inf+1".match_number(format).unwrap().1, "+1");
+    assert_eq!("-infinity)".match_number(format).unwrap().1, ")");
+}
+
+#[test]
+fn match_number_float_hexadecimal() {
+    let value = |data: &str| {
+        data.match_number(Float::<f64>::c())
+            .unwrap()
+            .0
+            .unwrap()
+            .1
+            .to_bits()
+    };
+
+    assert_eq!(value("0x1.8p3"), 12.0_f64.to_bits());
+    assert_eq!(value("-0X.8"), (-0.5_f64).to_bits());
+    assert_eq!(value("0x1F"), 31.0_f64.to_bits());
+    assert_eq!(value("0x1.fffffffffffffp1023"), f64::MAX.to_bits());
+    assert_eq!(value("0x1.fffffffffffff8p1023"), f64::INFINITY.to_bits());
+    assert_eq!(value("0x1p-1022"), f64::MIN_POSITIVE.to_bits());
+    assert_eq!(value("0x1p-1074"), 1);
+    assert_eq!(value("0x1p-1075"), 0.0_f64.to_bits());
+    assert_eq!(value("0x1.8p-1075"), 1);
+    assert_eq!(value("0x3p-1075"), 2);
+    assert_eq!(
+        value("0x1.0000000000000800000000001p0"),
+        (1.0 + f64::EPSILON).to_bits()
+    );
+    assert_eq!(value("0x1.00000000000008p0"), 1.0_f64.to_bits());
+
+    let value = |data: &str| {
+        data.match_number(Float::<f32>::c())
+            .unwrap()
+            .0
+            .unwrap()
+            .1
+            .to_bits()
+    };
+
+    assert_eq!(value("0x1.fffffep127"), f32::MAX.to_bits());
+    assert_eq!(value("0x1p-149"), 1);
+    assert_eq!(value("0x1.fffffcp-127"), 0x7f_ffff);
+}
+
+#[test]
+fn match_number_float_hexadecimal_fallback() {
+    let format: Float<f64> = Float::c();
+
+    assert_eq!("0x".match_number(format).unwrap(), (Some(("0", 0.0)), "x"));
+    assert_eq!(
+        "-0xg".match_number(format).unwrap(),
+        (Some(("-0", -0.0)), "xg")
+    );
+    assert_eq!(
+        "0x.p1".match_number(format).unwrap(),
+        (Some(("0", 0.0)), "x.p1")
+    );
+}
+
+#[test]
+fn match_number_float_rounding() {
+    let value = |data: &str| {
+        data.match_number(Float::<f64>::rust())
+            .unwrap()
+            .0
+            .unwrap()
+            .1
+            .to_bits()
+    };
+
+    // Halfway between 1 and the next value, rounded to even, and just above it.
+    assert_eq!(
+        value("1.00000000000000011102230246251565404236316680908203125"),
+        1.0_f64.to_bits()
+    );
+    assert_eq!(
+        value("1.00000000000000011102230246251565404236316680908203125000000001"),
+        (1.0 + f64::EPSILON).to_bits()
+    );
+    assert_eq!(value("4.9406564584124654e-324"), 1);
+    assert_eq!(value("1e-400"), 0.0_f64.to_bits());
+    assert_eq!(value("1e400"), f64::INFINITY.to_bits());
+    assert_eq!(value("0.000_000_1e1_0"), 1000.0_f64.to_bits());
+}
@@ -39,3 +39,11 @@ fn match_static_str() {
 fn match_static_str_panic() {
     match_static_str_test("#000000");
 }
+
+#[test]
+fn match_static_str_multi_byte() {
+    assert_eq!("标识".match_static("标").unwrap(), (Some("标"), "识"));
+    assert!("标识".match_static("ab").error().is_some());
+    assert!("я".match_static("ab").error().is_some());
+    assert!("ab".match_static("abc").error().is_some());
+}
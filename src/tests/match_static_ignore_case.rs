@@ -0,0 +1,114 @@
+use crate::{
+    input::{Located, Partial},
+    result::{Expected, Found, MatchFailed, Needed, Snippet},
+    traits::*,
+};
+
+#[test]
+fn match_static_ignore_case() {
+    let (name, rest) = b"Content-LENGTH: 42"
+        .match_static_ignore_case(b"content-length")
+        .unwrap();
+
+    assert_eq!(name, Some(&b"Content-LENGTH"[..]));
+    assert_eq!(rest, b": 42");
+
+    let (keyword, rest) = "Select * from t"
+        .match_static_ignore_case("SELECT")
+        .match_static(" * ")
+        .match_static_ignore_case("FROM")
+        .unwrap();
+
+    assert_eq!(keyword, Some("from"));
+    assert_eq!(rest, " t");
+}
+
+#[test]
+fn match_static_ignore_case_bytes_ascii_only() {
+    assert!(b"\xC3\x84"
+        .match_static_ignore_case(b"\xC3\xA4")
+        .rest()
+        .is_none());
+    assert_eq!(
+        b"HELlo".match_static_ignore_case(b"help").error(),
+        Some(&MatchFailed::new(
+            5,
+            Expected::Elements(4),
+            Found::Mismatch(3)
+        ))
+    );
+    assert_eq!(
+        b"He".match_static_ignore_case(b"help").error(),
+        Some(&MatchFailed::new(
+            2,
+            Expected::Elements(4),
+            Found::EndOfInput
+        ))
+    );
+}
+
+#[test]
+fn match_static_ignore_case_unicode() {
+    let (matched, rest) = "ÄRGER".match_static_ignore_case("ärg").unwrap();
+
+    assert_eq!(matched, Some("ÄRG"));
+    assert_eq!(rest, "ER");
+
+    // The final sigma and the Kelvin sign only match through their upper and lower case mappings respectively.
+    assert!("ΟΔΟΣ".match_static_ignore_case("οδος").rest().is_some());
+    assert!("ΟΔΟΣ".match_static_ignore_case("οδoς").rest().is_none());
+
+    let (matched, rest) = "\u{212A}m".match_static_ignore_case("km").unwrap();
+
+    assert_eq!(matched, Some("\u{212A}m"));
+    assert_eq!(rest, "");
+}
+
+#[test]
+fn match_static_ignore_case_failure() {
+    assert_eq!(
+        "Sekect".match_static_ignore_case("select").error(),
+        Some(&MatchFailed::new(
+            6,
+            Expected::Text(Snippet::new("select")),
+            Found::Text(Snippet::new("Sek"))
+        ))
+    );
+    assert_eq!(
+        "SEL".match_static_ignore_case("select").error(),
+        Some(&MatchFailed::new(
+            3,
+            Expected::Text(Snippet::new("select")),
+            Found::EndOfInput
+        ))
+    );
+}
+
+#[test]
+fn match_static_ignore_case_mapped() {
+    let (value, rest) = "TRUE"
+        .match_static_ignore_case_mapped("true", true)
+        .unwrap();
+
+    assert_eq!(value, Some(("TRUE", true)));
+    assert_eq!(rest, "");
+}
+
+#[test]
+fn match_static_ignore_case_input() {
+    let (matched, rest) = Located::new("Host: a")
+        .match_static_ignore_case("HOST")
+        .unwrap();
+
+    assert_eq!(*matched.unwrap().fragment(), "Host");
+    assert_eq!(rest.location().column(), 5);
+
+    assert_eq!(
+        Partial::new(&b"HO"[..])
+            .match_static_ignore_case(b"host")
+            .error()
+            .unwrap()
+            .needed(),
+        Some(Needed::Size(2))
+    );
+}
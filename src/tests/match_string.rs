@@ -0,0 +1,172 @@
+use crate::{
+    parser::{on_input, Contents, Quoted},
+    result::{Expected, Found, MappedMatch, MatchFailed, Snippet},
+    traits::*,
+};
+
+#[test]
+fn match_string() {
+    let (value, rest) = r#""abc" rest"#.match_string(Quoted::json()).unwrap();
+
+    assert_eq!(value, Some((r#""abc""#, Contents::Borrowed("abc"))));
+    assert_eq!(rest, " rest");
+
+    let (value, rest) = b"'' rest".match_string(Quoted::new(b'\'')).unwrap();
+
+    assert_eq!(value, Some((&b"''"[..], Contents::Borrowed(&b""[..]))));
+    assert_eq!(rest, b" rest");
+
+    let (value, _) = r#""a'b""#.match_string(Quoted::rust()).unwrap();
+
+    assert_eq!(value.unwrap().1.decoded(), Some("a'b"));
+}
+
+#[test]
+fn match_string_failure() {
+    for (data, format, expected) in [
+        (
+            "abc",
+            Quoted::json(),
+            MatchFailed::new(
+                3,
+                Expected::Text(Snippet::new("\"")),
+                Found::Text(Snippet::new("a")),
+            ),
+        ),
+        (
+            r#""abc"#,
+            Quoted::json(),
+            MatchFailed::new(0, Expected::Text(Snippet::new("\"")), Found::EndOfInput),
+        ),
+        (
+            r#""a\qb""#,
+            Quoted::json(),
+            MatchFailed::new(3, Expected::Escape, Found::Text(Snippet::new("q"))),
+        ),
+        (
+            r#""\u12G4""#,
+            Quoted::json(),
+            MatchFailed::new(3, Expected::Digit(16), Found::Text(Snippet::new("G"))),
+        ),
+        (
+            r#""\uD800x""#,
+            Quoted::json(),
+            MatchFailed::new(2, Expected::Escape, Found::Text(Snippet::new("x"))),
+        ),
+        (
+            r#""\uDC00""#,
+            Quoted::json(),
+            MatchFailed::new(6, Expected::Escape, Found::Text(Snippet::new("u"))),
+        ),
+        (
+            "\"a\nb\"",
+            Quoted::json(),
+            MatchFailed::new(3, Expected::Escape, Found::Control(b'\n')),
+        ),
+        (
+            "\"\u{1f}\"",
+            Quoted::json(),
+            MatchFailed::new(2, Expected::Escape, Found::Control(0x1F)),
+        ),
+        (
+            r#""\u{110000}""#,
+            Quoted::rust(),
+            MatchFailed::new(10, Expected::Escape, Found::Text(Snippet::new("u"))),
+        ),
+        (
+            r#""\u{1234567}""#,
+            Quoted::rust(),
+            MatchFailed::new(
+                3,
+                Expected::Text(Snippet::new("}")),
+                Found::Text(Snippet::new("7")),
+            ),
+        ),
+        (
+            r#""\u{}""#,
+            Quoted::rust(),
+            MatchFailed::new(2, Expected::Digit(16), Found::Text(Snippet::new("}"))),
+        ),
+        (
+            r#""\u41""#,
+            Quoted::rust(),
+            MatchFailed::new(
+                3,
+                Expected::Text(Snippet::new("{")),
+                Found::Text(Snippet::new("4")),
+            ),
+        ),
+        (
+            r#""\xFF""#,
+            Quoted::rust(),
+            MatchFailed::new(4, Expected::Escape, Found::Text(Snippet::new("x"))),
+        ),
+        (
+            r#""\x4"#,
+            Quoted::rust(),
+            MatchFailed::new(0, Expected::Digit(16), Found::EndOfInput),
+        ),
+        (
+            r#""ends with \"#,
+            Quoted::rust(),
+            MatchFailed::new(0, Expected::Escape, Found::EndOfInput),
+        ),
+    ] {
+        assert_eq!(
+            data.match_string(format).error(),
+            Some(&expected),
+            "matching {data:?}"
+        );
+    }
+
+    assert_eq!(
+        br#""a\qb""#.match_string(Quoted::json()).error(),
+        Some(&MatchFailed::new(3, Expected::Escape, Found::Mismatch(0)))
+    );
+}
+
+#[test]
+fn match_string_control() {
+    assert_eq!(
+        "\"a\tb\"".match_string(Quoted::rust()).unwrap().0,
+        Some(("\"a\tb\"", Contents::Borrowed("a\tb")))
+    );
+    assert_eq!(
+        b"'\n'".match_string(Quoted::new(b'\'')).unwrap().0,
+        Some((&b"'\n'"[..], Contents::Borrowed(&b"\n"[..])))
+    );
+    assert!(b"'\n'"
+        .match_string(Quoted::new(b'\'').forbid_control())
+        .is_failed());
+}
+
+#[test]
+fn match_string_escaped() {
+    let (value, _) = r#""a\nb""#.match_string(Quoted::json()).unwrap();
+    let (_, contents): (&str, Contents<'_, str>) = value.unwrap();
+
+    #[cfg(feature = "std")]
+    assert_eq!(contents.decoded(), Some("a\nb"));
+    #[cfg(not(feature = "std"))]
+    assert_eq!(contents, Contents::Escaped(r"a\nb"));
+}
+
+#[test]
+fn match_string_parser() {
+    let result: MappedMatch<&str, &str, Contents<'_, str>> = "'single'"
+        .mapped_alternatives()
+        .add_path(on_input(Quoted::json()))
+        .add_path(on_input(Quoted::new(b'\'')))
+        .finalize();
+
+    assert_eq!(
+        result.unwrap(),
+        (Some(("'single'", Contents::Borrowed("single"))), "")
+    );
+}
+
+#[test]
+#[should_panic(expected = "quote must be an ASCII character")]
+fn match_string_quote_panic() {
+    let _: Quoted<'_> = Quoted::new(0xAB);
+}
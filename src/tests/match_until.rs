@@ -0,0 +1,146 @@
+use crate::{
+    input::{Located, Partial},
+    result::{Expected, Found, Match, MatchFailed, Needed, Snippet},
+    traits::*,
+};
+
+#[test]
+fn match_until() {
+    let (comment, rest) = "<!-- note -- here --> text"
+        .match_static("<!--")
+        .match_until("-->")
+        .unwrap();
+
+    assert_eq!(comment, Some(" note -- here "));
+    assert_eq!(rest, "--> text");
+
+    let (line, rest) = b"GET / HTTP/1.1\r\nHost: a\r\n"
+        .match_until_including(b"\r\n")
+        .unwrap();
+
+    assert_eq!(line, Some(&b"GET / HTTP/1.1\r\n"[..]));
+    assert_eq!(rest, b"Host: a\r\n");
+
+    let (line, rest) = "no terminator".match_until("\r\n").unwrap();
+
+    assert_eq!(line, Some("no terminator"));
+    assert_eq!(rest, "");
+}
+
+#[test]
+fn match_until_empty_pattern() {
+    assert_eq!("abc".match_until("").unwrap(), (Some(""), "abc"));
+    assert_eq!(
+        b"abc".match_until_including(b"").unwrap(),
+        (Some(&b""[..]), &b"abc"[..])
+    );
+}
+
+#[test]
+fn match_until_found_failure() {
+    assert_eq!(
+        "a -> b".match_until_found("-->").error(),
+        Some(&MatchFailed::new(
+            0,
+            Expected::Text(Snippet::new("-->")),
+            Found::EndOfInput
+        ))
+    );
+    assert_eq!(
+        b"abc".match_until_including(b"cd").error(),
+        Some(&MatchFailed::new(
+            0,
+            Expected::Elements(2),
+            Found::EndOfInput
+        ))
+    );
+}
+
+#[test]
+fn match_until_multi_byte() {
+    let (matched, rest) = "标识€标识".match_until_including("€").unwrap();
+
+    assert_eq!(matched, Some("标识€"));
+    assert_eq!(rest, "标识");
+}
+
+#[test]
+fn match_until_mapped() {
+    let (value, rest) = "key=value".match_until_found_mapped("=", 1).unwrap();
+
+    assert_eq!(value, Some(("key", 1)));
+    assert_eq!(rest, "=value");
+}
+
+#[test]
+fn match_until_located() {
+    let (matched, rest) = Located::new("first\nsecond\r\nthird")
+        .match_until_including("\r\n")
+        .unwrap();
+
+    assert_eq!(*matched.unwrap().fragment(), "first\nsecond\r\n");
+    assert_eq!(rest.location().line(), 3);
+    assert_eq!(rest.location().column(), 1);
+}
+
+#[test]
+fn match_until_partial() {
+    let result = Partial::new("line without end").match_until("\n");
+
+    assert_eq!(result.error().unwrap().needed(), Some(Needed::Size(1)));
+    assert!(Partial::new("some\n").match_until("\n").rest().is_some());
+    assert!(Partial::last("some").match_until("\n").rest().is_some());
+
+    let result = Partial::new("-").match_until_found("-->");
+
+    assert_eq!(result.error().unwrap().needed(), Some(Needed::Size(2)));
+    assert!(!Partial::last("-")
+        .match_until_found("-->")
+        .error()
+        .unwrap()
+        .is_incomplete());
+}
+
+/// Compares the search against a naive one on exhaustively enumerated short inputs over a small alphabet.
+#[test]
+fn match_until_exhaustive() {
+    fn generate(mut seed: usize, length: usize) -> [u8; 8] {
+        let mut data: [u8; 8] = [0; 8];
+
+        for element in &mut data[..length] {
+            *element = b"ab"[seed % 2];
+            seed /= 2;
+        }
+
+        data
+    }
+
+    for pattern_length in 1..=4 {
+        for pattern_seed in 0..(1 << pattern_length) {
+            let pattern: [u8; 8] = generate(pattern_seed, pattern_length);
+            let pattern: &[u8] = &pattern[..pattern_length];
+
+            for data_length in 0..=8 {
+                for data_seed in 0..(1 << data_length) {
+                    let data: [u8; 8] = generate(data_seed, data_length);
+                    let data: &[u8] = &data[..data_length];
+
+                    let expected: Option<usize> = data
+                        .windows(pattern_length)
+                        .position(|window| window == pattern);
+
+                    let result: Match<&[u8], &[u8]> = data.match_until_found(pattern);
+
+                    assert_eq!(
+                        result
+                            .take()
+                            .ok()
+                            .map(|(matched, _)| matched.unwrap().len()),
+                        expected,
+                        "searching {pattern:?} in {data:?}"
+                    );
+                }
+            }
+        }
+    }
+}
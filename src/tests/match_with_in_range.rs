@@ -0,0 +1,162 @@
+use crate::{
+    result::{Expected, Found, Match},
+    traits::*,
+};
+
+fn digits<B>(data: &[u8], range: B) -> Match<&[u8], &[u8]>
+where
+    B: Repetition,
+{
+    data.match_range_with(range, |x: u8| x.is_ascii_digit())
+}
+
+#[test]
+fn match_range_with() {
+    assert_eq!(
+        digits(b"12345a", 2..).unwrap(),
+        (Some(&b"12345"[..]), &b"a"[..])
+    );
+    assert_eq!(
+        digits(b"12345a", ..=3).unwrap(),
+        (Some(&b"123"[..]), &b"45a"[..])
+    );
+    assert_eq!(
+        digits(b"12345a", 3..5).unwrap(),
+        (Some(&b"1234"[..]), &b"5a"[..])
+    );
+    assert_eq!(
+        digits(b"12345a", 4).unwrap(),
+        (Some(&b"1234"[..]), &b"5a"[..])
+    );
+    assert_eq!(
+        digits(b"12345a", ..).unwrap(),
+        (Some(&b"12345"[..]), &b"a"[..])
+    );
+    assert_eq!(
+        digits(b"12345a", 2_u64..).unwrap(),
+        (Some(&b"12345"[..]), &b"a"[..])
+    );
+    assert_eq!(digits(b"a", 0).unwrap(), (Some(&b""[..]), &b"a"[..]));
+}
+
+#[test]
+fn match_range_with_failed() {
+    let error = digits(b"12a", 3..).error().copied().unwrap();
+
+    assert_eq!(error.expected(), &Expected::AtLeast(3));
+    assert_eq!(error.found(), &Found::Count(2));
+
+    let error = digits(b"12", 3..=4).error().copied().unwrap();
+
+    assert_eq!(error.expected(), &Expected::Between(3, 4));
+    assert_eq!(error.found(), &Found::EndOfInput);
+
+    assert!(digits(b"12a", -1..3).error().is_none());
+}
+
+#[test]
+fn match_range_with_empty() {
+    let (start, end): (i32, i32) = (5, 2);
+
+    for error in &[
+        digits(b"123", 3..3).error().copied(),
+        digits(b"123", start..=end).error().copied(),
+        digits(b"123", ..0).error().copied(),
+    ] {
+        assert_eq!(error.unwrap().expected(), &Expected::Nothing);
+    }
+}
+
+#[test]
+fn match_exact_with_oversized() {
+    assert!(b"".match_exact_with(u128::MAX, |_: u8| true).is_failed());
+    assert!(b"123".match_min_with(u64::MAX, |_: u8| true).is_failed());
+    assert!("".match_exact_with(i128::MAX, |_: char| true).is_failed());
+
+    assert_eq!(
+        b"123".match_max_with(u128::MAX, |_: u8| true).unwrap(),
+        (Some(&b"123"[..]), &b""[..])
+    );
+}
+
+#[test]
+fn match_min_max_with() {
+    assert_eq!(
+        b"12345a"
+            .match_min_max_with(2, 4, |x: &u8| x.is_ascii_digit())
+            .unwrap(),
+        (Some(&b"1234"[..]), &b"5a"[..])
+    );
+}
+
+#[test]
+fn match_range_with_str() {
+    assert_eq!(
+        "12345a"
+            .match_range_with(2..4, |c: char| c.is_ascii_digit())
+            .unwrap(),
+        (Some("123"), "45a")
+    );
+    assert_eq!(
+        "12345a"
+            .match_static("1")
+            .match_range_with(..=2_i64, |c: &char| c.is_ascii_digit())
+            .unwrap(),
+        (Some("23"), "45a")
+    );
+}
+
+#[test]
+fn match_range_with_mapped() {
+    assert_eq!(
+        "12345a"
+            .match_range_with_mapped(1.., |c: char| c.is_ascii_digit(), 12345)
+            .unwrap(),
+        (Some(("12345", 12345)), "a")
+    );
+}
+
+fn letters<B>(data: &str, range: B) -> Match<&str, &str>
+where
+    B: Repetition,
+{
+    data.match_range_with(range, |c: char| c.is_alphabetic())
+}
+
+#[test]
+fn match_range_with_multi_byte() {
+    assert_eq!(letters("привет мир", 3).unwrap(), (Some("при"), "вет мир"));
+    assert_eq!(
+        letters("привет мир", ..=4).unwrap(),
+        (Some("прив"), "ет мир")
+    );
+    assert_eq!(letters("标识符 x", 2..).unwrap(), (Some("标识符"), " x"));
+    assert_eq!(letters("标识符 x", 3).unwrap(), (Some("标识符"), " x"));
+    assert_eq!(
+        "标识符"
+            .match_exact_with(2, |c: &char| c.is_alphabetic())
+            .unwrap(),
+        (Some("标识"), "符")
+    );
+
+    let error = letters("ид 1", 3..).error().copied().unwrap();
+
+    assert_eq!(error.expected(), &Expected::AtLeast(3));
+    assert_eq!(error.found(), &Found::Count(2));
+}
+
+#[test]
+fn match_range_with_bytes() {
+    assert_eq!(letters("привет", Bytes(4)).unwrap(), (Some("пр"), "ивет"));
+    assert_eq!(
+        letters("привет", Bytes(..=5)).unwrap(),
+        (Some("пр"), "ивет")
+    );
+    assert_eq!(letters("标识符", Bytes(..6)).unwrap(), (Some("标"), "识符"));
+    assert_eq!(letters("标识符", Bytes(6..)).unwrap(), (Some("标识符"), ""));
+
+    let error = letters("标识符", Bytes(4)).error().copied().unwrap();
+
+    assert_eq!(error.expected(), &Expected::Exactly(4));
+    assert_eq!(error.found(), &Found::Count(3));
+}
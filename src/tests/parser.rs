@@ -0,0 +1,53 @@
+use crate::{
+    parser::{on_input, on_rest, Parser, Static, With},
+    result::Match,
+    traits::*,
+};
+
+fn digits(input: &str) -> Match<&str, &str> {
+    input.match_min_with(1, |c: char| c.is_ascii_digit())
+}
+
+fn spaces() -> impl Parser<&'static str, Match<&'static str, &'static str>, With<char>> {
+    With::new(char::is_whitespace)
+}
+
+fn parser_test(data: &'static str) {
+    let mut sign = Static::new("-");
+
+    let _ = data
+        .alternatives::<&str, &str>()
+        .add_path(on_input(&mut sign))
+        .add_path(on_input(Static::new("+")))
+        .finalize()
+        .discarding(on_rest(spaces()))
+        .discarding(on_rest(digits))
+        .discarding(on_rest(spaces()))
+        .discarding(on_rest(&mut sign))
+        .unwrap();
+}
+
+#[test]
+fn parser() {
+    parser_test("- 123 -");
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn parser_panic() {
+    parser_test("- abc -");
+}
+
+#[test]
+fn parser_rule() {
+    let mut number = |input: &'static str| {
+        input
+            .match_static("-")
+            .optional(on_rest(digits))
+            .assert(|matched, _| matched.is_some())
+    };
+
+    assert!(number.parse("-12").rest().is_some());
+    assert!(number.parse("12").is_failed());
+    assert!(number.parse("-").rest().is_some());
+}
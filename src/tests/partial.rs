@@ -0,0 +1,105 @@
+use crate::{
+    input::Partial,
+    result::{MatchFailed, Needed},
+    traits::*,
+};
+
+fn partial_test(data: Partial<&[u8]>) -> Result<(), MatchFailed> {
+    data.match_static(b"GET ")
+        .match_min_with(1, |byte: u8| byte != b' ')
+        .match_static(b" HTTP/1.1\r\n")
+        .take()
+        .map(|_| ())
+}
+
+#[test]
+fn partial() {
+    assert_eq!(
+        partial_test(Partial::new(b"GET /index.html HTTP/1.1\r\n")),
+        Ok(())
+    );
+}
+
+#[test]
+fn partial_incomplete() {
+    for (data, needed) in [
+        (&b"GE"[..], Needed::Size(2)),
+        (b"GET ", Needed::Size(1)),
+        (b"GET /index", Needed::Size(1)),
+        (b"GET /index.html HT", Needed::Size(8)),
+    ] {
+        let error: MatchFailed = partial_test(Partial::new(data)).unwrap_err();
+
+        assert!(error.is_incomplete());
+        assert_eq!(error.needed(), Some(needed));
+
+        assert!(!partial_test(Partial::last(data))
+            .unwrap_err()
+            .is_incomplete());
+    }
+}
+
+#[test]
+fn partial_failed() {
+    let error: MatchFailed = partial_test(Partial::new(b"PUT /index")).unwrap_err();
+
+    assert!(!error.is_incomplete());
+}
+
+#[test]
+fn partial_max() {
+    let data: Partial<&str> = Partial::new("абв");
+
+    assert!(data.match_max_with(3, char::is_alphabetic).rest().is_some());
+    assert!(data.match_max_with(4, char::is_alphabetic).is_incomplete());
+}
+
+#[test]
+fn partial_multi_byte() {
+    let data: Partial<&str> = Partial::new("标识");
+
+    assert!(data
+        .match_exact_with(2, char::is_alphabetic)
+        .rest()
+        .is_some());
+    assert_eq!(
+        data.match_exact_with(4, char::is_alphabetic)
+            .error()
+            .unwrap()
+            .needed(),
+        Some(Needed::Size(2))
+    );
+    assert_eq!(
+        data.match_range_with(Bytes(7), char::is_alphabetic)
+            .error()
+            .unwrap()
+            .needed(),
+        Some(Needed::Size(1))
+    );
+    assert!(data
+        .match_range_with(Bytes(..=6), char::is_alphabetic)
+        .rest()
+        .is_some());
+    assert!(data
+        .match_range_with(Bytes(..=7), char::is_alphabetic)
+        .is_incomplete());
+}
+
+#[test]
+fn partial_alternatives() {
+    let data: Partial<&str> = Partial::new("ab");
+
+    let result = data
+        .alternatives::<Partial<&str>, Partial<&str>>()
+        .add_path(|rest| rest.match_static("abc"))
+        .add_path(|rest| rest.match_static("a"))
+        .finalize();
+
+    assert!(result.is_incomplete());
+
+    let result = data
+        .match_static("a")
+        .optional(|_, rest| rest.match_static("bc"));
+
+    assert!(result.is_incomplete());
+}
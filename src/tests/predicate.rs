@@ -0,0 +1,107 @@
+use crate::{
+    input::{Located, Partial},
+    predicate::*,
+    result::Match,
+    traits::*,
+};
+
+#[test]
+fn predicate_classes() {
+    let (matched, rest) = "#1a2B3c"
+        .match_static("#")
+        .match_exact_with(6, HexDigit)
+        .unwrap();
+
+    assert_eq!(matched, Some("1a2B3c"));
+    assert_eq!(rest, "");
+
+    let (matched, rest) = b"_id1 = 2"
+        .match_exact_with(1, IdentifierStart)
+        .match_with(IdentifierContinue)
+        .match_with(Whitespace)
+        .unwrap();
+
+    assert_eq!(matched, Some(&b" "[..]));
+    assert_eq!(rest, b"= 2");
+
+    assert!("1x".match_min_with(1, IdentifierStart).rest().is_none());
+    assert!("число".match_min_with(1, Alphabetic).rest().is_some());
+    assert!(b"\xD1\x87".match_min_with(1, Alphabetic).rest().is_none());
+}
+
+#[test]
+fn predicate_sets() {
+    let (matched, rest) = "abcxyz".match_with('a'..='c').unwrap();
+
+    assert_eq!(matched, Some("abc"));
+    assert_eq!(rest, "xyz");
+
+    let (matched, _) = "+-+*".match_with("+-").unwrap();
+
+    assert_eq!(matched, Some("+-+"));
+
+    let (matched, _) = "+-+*".match_with(['+', '*']).unwrap();
+
+    assert_eq!(matched, Some("+"));
+
+    let (matched, _) = b"0123456789".match_with(b'0'..b'8').unwrap();
+
+    assert_eq!(matched, Some(&b"01234567"[..]));
+
+    let (matched, _) = [1, 2, 3, 4].match_with(&[2, 1][..]).unwrap();
+
+    assert_eq!(matched, Some(&[1, 2][..]));
+}
+
+#[test]
+fn predicate_composition() {
+    let sign = ['+', '-'];
+    let number = Union(Union(Digit, sign), Satisfy(|c: &char| *c == '.'));
+
+    let (matched, rest) = "-12.5e3".match_with(&number).unwrap();
+
+    assert_eq!(matched, Some("-12.5"));
+    assert_eq!(rest, "e3");
+
+    let (matched, rest) = "abc1"
+        .match_with(Intersection(Alphanumeric, Complement(Digit)))
+        .unwrap();
+
+    assert_eq!(matched, Some("abc"));
+    assert_eq!(rest, "1");
+
+    assert!(number.test(&'+'));
+    assert!(!Complement(&number).test(&'+'));
+}
+
+#[test]
+fn predicate_byte_set() {
+    let set: ByteSet = ByteSet::of(Union(HexDigit, b"_"));
+
+    for byte in 0..=u8::MAX {
+        assert_eq!(set.contains(byte), byte.is_ascii_hexdigit() || byte == b'_');
+    }
+
+    assert_eq!(ByteSet::new().with(0).with(255), ByteSet::of([0, 255]));
+
+    let (matched, rest) = b"dead_beef!".match_max_with(8, set).unwrap();
+
+    assert_eq!(matched, Some(&b"dead_bee"[..]));
+    assert_eq!(rest, b"f!");
+}
+
+#[test]
+fn predicate_inputs() {
+    let (matched, rest) = Located::new("  \n x").match_with(Whitespace).unwrap();
+
+    assert_eq!(*matched.unwrap().fragment(), "  \n ");
+    assert_eq!(rest.location().line(), 2);
+
+    let result: Match<Partial<&str>, Partial<&str>> = Partial::new("123").match_with(Digit);
+
+    assert!(result.is_incomplete());
+
+    let (value, _) = "42".match_with_mapped(Digit, 0).unwrap();
+
+    assert_eq!(value, Some(("42", 0)));
+}
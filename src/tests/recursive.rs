@@ -0,0 +1,128 @@
+use crate::{
+    parser::{on_input, Forward, Parser, Recursive},
+    result::{Found, MappedMatch, Match},
+    traits::*,
+};
+
+fn parentheses<'a>(input: &'a str, limit: usize) -> Match<&'a str, &'a str> {
+    Recursive::with_limit(
+        |this: &mut dyn FnMut(&'a str) -> Match<&'a str, &'a str>, input: &'a str| {
+            input
+                .match_static("(")
+                .optional(|_, rest| this(rest))
+                .match_static(")")
+        },
+        limit,
+    )
+    .parse(input)
+}
+
+#[test]
+fn recursive() {
+    assert_eq!(parentheses("((()))", 4).unwrap(), (Some(")"), ""));
+}
+
+#[test]
+fn recursive_limit() {
+    let error = parentheses("((()))", 3).take().unwrap_err();
+
+    assert_eq!(error.found(), &Found::RecursionLimit(3));
+}
+
+#[test]
+fn recursive_limit_position() {
+    let error = parentheses("((()))", 3).take().unwrap_err();
+
+    assert_eq!(error.remaining(), Some(3));
+    assert_eq!(error.position(6), Some(3));
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn recursive_panic() {
+    let _ = parentheses("((())", 4).unwrap();
+}
+
+fn nesting_test<'a>(data: &'a str) -> usize {
+    let array: Forward<&'a str, MappedMatch<&'a str, &'a str, usize>> = Forward::new();
+
+    let rule = |input: &'a str| {
+        input
+            .match_static_mapped("[", 1)
+            .optional_ref(|_, &rest| array.parse(rest).transform_mapped(|depth| depth + 1))
+            .discarding_ref(|_, rest| rest.match_static_mapped("]", 0))
+    };
+
+    array.define(&rule);
+
+    let (matched, rest) = data
+        .mapped_alternatives()
+        .add_path(on_input(&array))
+        .finalize()
+        .unwrap();
+
+    assert!(rest.is_empty());
+
+    matched.unwrap().1
+}
+
+#[test]
+fn forward() {
+    assert_eq!(nesting_test("[]"), 1);
+    assert_eq!(nesting_test("[[[]]]"), 3);
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn forward_panic() {
+    nesting_test("[[]");
+}
+
+#[test]
+fn forward_limit() {
+    let array: Forward<&'static str, Match<&'static str, &'static str>> = Forward::with_limit(16);
+
+    let rule = |input: &'static str| {
+        input
+            .match_static("[")
+            .optional_ref(|_, &rest| array.parse(rest))
+            .match_static("]")
+    };
+
+    array.define(&rule);
+
+    let data: &str = "[[[[[[[[[[[[[[[[[[[[]]]]]]]]]]]]]]]]]]]]";
+
+    let error = array.parse(data).take().unwrap_err();
+
+    assert_eq!(error.found(), &Found::RecursionLimit(16));
+    assert!(array.parse(&data[8..32]).rest().is_some());
+}
+
+#[test]
+fn forward_limit_position() {
+    let array: Forward<&'static str, Match<&'static str, &'static str>> = Forward::with_limit(16);
+
+    let rule = |input: &'static str| {
+        input
+            .match_static("[")
+            .optional_ref(|_, &rest| array.parse(rest))
+            .match_static("]")
+    };
+
+    array.define(&rule);
+
+    let data: &str = "[[[[[[[[[[[[[[[[[[[[]]]]]]]]]]]]]]]]]]]]";
+
+    let error = array.parse(data).take().unwrap_err();
+
+    assert_eq!(error.position(data.len()), Some(16));
+}
+
+#[test]
+fn forward_undefined() {
+    let rule: Forward<&str, Match<&str, &str>> = Forward::new();
+
+    assert!(!rule.is_defined());
+    assert!(rule.parse("[]").is_failed());
+}
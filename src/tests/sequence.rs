@@ -0,0 +1,111 @@
+use crate::{input::Located, result::Match, traits::*};
+
+fn sequence_test(data: &[u8]) -> (&[u8], &[u8], &[u8]) {
+    let (matched, _) = data
+        .sequence()
+        .then(|rest| rest.match_static(b"#"))
+        .then(|rest| rest.match_exact_with(2, |byte: u8| byte.is_ascii_digit()))
+        .skip(|rest| rest.match_with(|byte: u8| byte == b' '))
+        .then(|rest| rest.match_exact_with(2, |byte: u8| byte.is_ascii_digit()))
+        .finalize()
+        .unwrap();
+
+    matched.unwrap()
+}
+
+#[test]
+fn sequence() {
+    assert_eq!(
+        sequence_test(b"#12  34"),
+        (&b"#"[..], &b"12"[..], &b"34"[..])
+    );
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn sequence_panic() {
+    sequence_test(b"#12 AB");
+}
+
+fn sequence_str_test(data: &str) -> (&str, u8, &str) {
+    let (matched, _) = data
+        .match_static("#")
+        .into_sequence()
+        .then(|rest| rest.match_exact_with(2, |c: char| c.is_ascii_hexdigit()))
+        .then(|rest| {
+            rest.match_exact_with_mapped(2, |c: char| c.is_ascii_hexdigit(), ())
+                .transform_full(|matched, rest, ()| {
+                    crate::result::TransformMappedMatch::Full(
+                        u8::from_str_radix(matched, 16).unwrap(),
+                        rest,
+                        (),
+                    )
+                })
+                .unmap(|()| {})
+        })
+        .then(|rest| rest.match_exact_with(2, |c: char| c.is_ascii_hexdigit()))
+        .finalize()
+        .unwrap();
+
+    matched.unwrap()
+}
+
+#[test]
+fn sequence_str() {
+    assert_eq!(sequence_str_test("#12FF34"), ("12", 255, "34"));
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn sequence_str_panic() {
+    sequence_str_test("#12FFXY");
+}
+
+#[test]
+fn recognize() {
+    let data: &[u8] = b"#12.34;";
+
+    let (matched, rest): (Option<&[u8]>, &[u8]) = data
+        .recognize(|rest: &[u8]| {
+            rest.match_static(b"#")
+                .match_min_with(1, |byte: u8| byte.is_ascii_digit())
+                .optional(|_, rest| {
+                    rest.match_static(b".")
+                        .match_min_with(1, |byte: u8| byte.is_ascii_digit())
+                })
+        })
+        .unwrap();
+
+    assert_eq!(matched, Some(&b"#12.34"[..]));
+    assert_eq!(rest, b";");
+}
+
+#[test]
+fn recognize_str() {
+    let result: Match<&str, &str> = "#12.34;".match_static("#").recognize(|rest: &str| {
+        rest.match_min_with(1, |c: char| c.is_ascii_digit())
+            .match_static(".")
+            .match_min_with(1, |c: char| c.is_ascii_digit())
+    });
+
+    assert_eq!(result.unwrap(), (Some("12.34"), ";"));
+    assert!("#12;"
+        .recognize(|rest: &str| rest.match_static("#").match_static("."))
+        .is_failed());
+}
+
+#[test]
+fn sequence_located() {
+    let (matched, rest) = Located::new("#12 ab")
+        .sequence()
+        .then(|rest| rest.match_static("#"))
+        .then(|rest| rest.match_exact_with(2, |c: char| c.is_ascii_digit()))
+        .finalize()
+        .unwrap();
+
+    let (hash, digits): (Located<&str>, Located<&str>) = matched.unwrap();
+
+    assert_eq!(hash.into_fragment(), "#");
+    assert_eq!(digits.into_fragment(), "12");
+    assert_eq!(rest.into_fragment(), " ab");
+}
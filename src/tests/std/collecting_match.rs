@@ -1,4 +1,8 @@
-use crate::traits::*;
+use crate::{
+    input::Partial,
+    result::{Expected, Found, Match, MatchFailed, Needed},
+    traits::*,
+};
 
 fn collecting_match_test(data: &[u8]) {
     let _ = data
@@ -45,7 +49,7 @@ fn collecting_match_str_panic() {
 }
 
 fn collecting_match_repeat_test(data: &[u8]) {
-    let _ = data
+    let (matches, _) = data
         .match_static(b"#")
         .into_collecting()
         .repeat(3, |_, rest: &[u8]| {
@@ -53,6 +57,8 @@ fn collecting_match_repeat_test(data: &[u8]) {
         })
         .finalize()
         .unwrap();
+
+    assert_eq!(matches, [&b"#"[..], b"12", b"34", b"56"]);
 }
 
 #[test]
@@ -67,7 +73,7 @@ fn collecting_match_repeat_panic() {
 }
 
 fn collecting_match_repeat_str_test(data: &str) {
-    let _ = data
+    let (matches, _) = data
         .match_static("#")
         .into_collecting()
         .repeat(3, |_, rest: &str| {
@@ -75,6 +81,8 @@ fn collecting_match_repeat_str_test(data: &str) {
         })
         .finalize()
         .unwrap();
+
+    assert_eq!(matches, ["#", "12", "34", "56"]);
 }
 
 #[test]
@@ -87,3 +95,163 @@ fn collecting_match_repeat_str() {
 fn collecting_match_repeat_str_panic() {
     collecting_match_repeat_str_test("#ABCDEF");
 }
+
+#[test]
+fn collecting_match_failed() {
+    let data: &str = "#12AB";
+
+    let error: MatchFailed = data
+        .match_static("#")
+        .into_collecting()
+        .single(|_, rest: &str| rest.match_exact_with(2, |c: char| c.is_numeric()))
+        .single(|_, rest: &str| rest.match_exact_with(2, |c: char| c.is_numeric()))
+        .finalize()
+        .unwrap_err();
+
+    assert_eq!(error.position(data.len()), Some(3));
+    assert_eq!(error.expected(), &Expected::Exactly(2));
+}
+
+#[test]
+fn collecting_match_incomplete() {
+    let result: Result<_, MatchFailed> = Partial::new("#12 3")
+        .match_static("#")
+        .into_collecting()
+        .single(|_, rest| rest.match_exact_with(2, |c: char| c.is_numeric()))
+        .discarding(|_, rest| rest.match_static(" "))
+        .single(|_, rest| rest.match_exact_with(2, |c: char| c.is_numeric()))
+        .finalize();
+
+    assert_eq!(result.unwrap_err().needed(), Some(Needed::Size(1)));
+}
+
+fn item<'a>(_: Option<&&str>, rest: &'a str) -> Match<&'a str, &'a str> {
+    rest.match_static(" ")
+        .match_min_with(1, char::is_alphanumeric)
+}
+
+#[test]
+fn collecting_match_repeat_while() {
+    let (matches, rest) = "[ a bc d]"
+        .match_static("[")
+        .into_collecting()
+        .repeat_while(item)
+        .unwrap();
+
+    assert_eq!(matches, ["[", "a", "bc", "d"]);
+    assert_eq!(rest, "]");
+
+    let (matches, rest) = "abc"
+        .match_static("a")
+        .into_collecting()
+        .repeat_while(|_, rest: &str| rest.match_max_with(3, char::is_numeric))
+        .unwrap();
+
+    assert_eq!(matches, ["a"]);
+    assert_eq!(rest, "bc");
+}
+
+#[test]
+fn collecting_match_repeat_range() {
+    let (matches, rest) = "[ a bc d]"
+        .match_static("[")
+        .into_collecting()
+        .repeat_range(1..=2, item)
+        .unwrap();
+
+    assert_eq!(matches, ["[", "a", "bc"]);
+    assert_eq!(rest, " d]");
+
+    let error: MatchFailed = "[ a bc d]"
+        .match_static("[")
+        .into_collecting()
+        .repeat_range(4.., item)
+        .finalize()
+        .unwrap_err();
+
+    assert_eq!(error.position("[ a bc d]".len()), Some(8));
+
+    assert_eq!(
+        "[]".match_static("[")
+            .into_collecting()
+            .repeat_range(2..2, item)
+            .finalize()
+            .unwrap_err()
+            .expected(),
+        &Expected::Nothing
+    );
+}
+
+#[test]
+fn collecting_match_repeat_until() {
+    let (matches, rest) = "[ a bc d]!"
+        .match_static("[")
+        .into_collecting()
+        .repeat_until(|_, rest: &str| rest.match_static("]"), item)
+        .unwrap();
+
+    assert_eq!(matches, ["[", "a", "bc", "d"]);
+    assert_eq!(rest, "!");
+
+    let (matches, _) = "[]"
+        .match_static("[")
+        .into_collecting()
+        .repeat_until(|_, rest: &str| rest.match_static("]"), item)
+        .unwrap();
+
+    assert_eq!(matches, ["["]);
+
+    assert!("[ a bc d"
+        .match_static("[")
+        .into_collecting()
+        .repeat_until(|_, rest: &str| rest.match_static("]"), item)
+        .is_failed());
+}
+
+#[test]
+fn collecting_match_repeat_incomplete() {
+    let result = Partial::new("[ a bc")
+        .match_static("[")
+        .into_collecting()
+        .repeat_while(|_, rest| {
+            rest.match_static(" ")
+                .match_min_with(1, char::is_alphanumeric)
+        });
+
+    assert!(result.is_incomplete());
+}
+
+#[test]
+fn collecting_match_lookahead() {
+    let (matches, rest) = "abc1"
+        .into_match::<&str>()
+        .into_collecting()
+        .repeat_while(|_, rest: &str| {
+            rest.match_exact_with(1, char::is_alphabetic)
+                .not(|_, rest| rest.match_exact_with(1, char::is_numeric))
+        })
+        .peek(|last, rest| {
+            assert_eq!(last, Some(&"b"));
+
+            rest.match_static("c")
+        })
+        .not(|_, rest| rest.match_static("1"))
+        .unwrap();
+
+    assert_eq!(matches, ["a", "b"]);
+    assert_eq!(rest, "c1");
+}
+
+#[test]
+fn collecting_match_not_position() {
+    let result = "ab1"
+        .into_match::<&str>()
+        .into_collecting()
+        .repeat_while(|_, rest: &str| rest.match_exact_with(1, char::is_alphabetic))
+        .not(|_, rest| rest.match_static("1"));
+
+    assert_eq!(
+        result.finalize(),
+        Err(MatchFailed::new(1, Expected::Unknown, Found::Unexpected))
+    );
+}
@@ -0,0 +1,129 @@
+use crate::{
+    parser::{on_input, Keywords},
+    result::{Expected, Found, MappedMatch, MatchFailed, Snippet},
+    traits::*,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Keyword {
+    Select,
+    Set,
+    From,
+}
+
+fn sql_keywords() -> Keywords<u8, Keyword> {
+    Keywords::new()
+        .add("select", Keyword::Select)
+        .add("set", Keyword::Set)
+        .add("from", Keyword::From)
+}
+
+#[test]
+fn keywords() {
+    let keywords: Keywords<u8, Keyword> = sql_keywords();
+
+    let (keyword, rest) = "select a from t".match_keywords(&keywords).unwrap();
+
+    assert_eq!(keyword, Some(("select", Keyword::Select)));
+    assert_eq!(rest, " a from t");
+
+    let (keyword, rest) = "select a from t"
+        .match_static("select a ")
+        .match_keywords(&keywords)
+        .unwrap();
+
+    assert_eq!(keyword, Some(("from", Keyword::From)));
+    assert_eq!(rest, " t");
+}
+
+#[test]
+fn keywords_longest() {
+    let keywords: Keywords<u8> = Keywords::indexed(["<", "<=", "<<", "<<="]);
+
+    for (data, index, rest) in [
+        ("<<= 1", 3, " 1"),
+        ("<< 1", 2, " 1"),
+        ("<=<", 1, "<"),
+        ("<", 0, ""),
+    ] {
+        assert_eq!(
+            data.match_keywords(&keywords).unwrap(),
+            (Some((&data[..data.len() - rest.len()], index)), rest)
+        );
+    }
+}
+
+#[test]
+fn keywords_slice() {
+    let keywords: Keywords<u32> = [(&[1, 2][..], 0), (&[1, 2, 3, 4][..], 1)]
+        .iter()
+        .copied()
+        .collect();
+
+    let (value, rest) = [1, 2, 3, 5].match_keywords(&keywords).unwrap();
+
+    assert_eq!(value, Some((&[1, 2][..], 0)));
+    assert_eq!(rest, [3, 5]);
+}
+
+#[test]
+fn keywords_failure() {
+    let keywords: Keywords<u8, Keyword> = sql_keywords();
+
+    assert_eq!(
+        "sex"
+            .match_keywords(&keywords.clone().add("", Keyword::Set))
+            .unwrap(),
+        (Some(("", Keyword::Set)), "sex")
+    );
+
+    assert_eq!(
+        "sex".match_keywords(&keywords).error(),
+        Some(&MatchFailed::new(
+            3,
+            Expected::Text(Snippet::new("set")),
+            Found::Text(Snippet::new("sex"))
+        ))
+    );
+    assert_eq!(
+        "sel".match_keywords(&keywords).error(),
+        Some(&MatchFailed::new(
+            3,
+            Expected::Text(Snippet::new("select")),
+            Found::EndOfInput
+        ))
+    );
+    assert_eq!(
+        b"upsert"
+            .match_keywords(&Keywords::indexed(["update"]))
+            .error(),
+        Some(&MatchFailed::new(
+            6,
+            Expected::Elements(6),
+            Found::Mismatch(2)
+        ))
+    );
+}
+
+#[test]
+fn keywords_multi_byte() {
+    let keywords: Keywords<u8> = Keywords::indexed([&b"\xD0"[..], "д".as_bytes(), "да".as_bytes()]);
+
+    let (value, rest) = "дб".match_keywords(&keywords).unwrap();
+
+    assert_eq!(value, Some(("д", 1)));
+    assert_eq!(rest, "б");
+}
+
+#[test]
+fn keywords_parser() {
+    let keywords: Keywords<u8, Keyword> = sql_keywords();
+
+    let result: MappedMatch<&str, &str, Keyword> = "set"
+        .mapped_alternatives()
+        .add_path(on_input(&keywords))
+        .add_path(|rest: &str| rest.match_static_mapped("s", Keyword::Set))
+        .finalize();
+
+    assert_eq!(result.unwrap(), (Some(("set", Keyword::Set)), ""));
+}
@@ -0,0 +1,44 @@
+use crate::traits::*;
+
+#[test]
+fn match_alternatives_matched_branches() {
+    let alternatives = "int x"
+        .alternatives::<&str, &str>()
+        .exhaustive()
+        .add_path(|rest| rest.match_static("in"))
+        .add_path(|rest| rest.match_static("for"))
+        .add_path(|rest| rest.match_min_with(1, char::is_alphabetic));
+
+    assert_eq!(alternatives.matched_branches(), [0, 2]);
+
+    let (result, index) = alternatives.finalize_indexed();
+
+    assert_eq!(result.unwrap(), (Some("in"), "t x"));
+    assert_eq!(index, Some(0));
+}
+
+#[test]
+fn match_alternatives_matched_branches_first() {
+    let alternatives = "int x"
+        .alternatives::<&str, &str>()
+        .add_path(|rest| rest.match_static("in"))
+        .add_path(|rest| rest.match_min_with(1, char::is_alphabetic));
+
+    assert_eq!(alternatives.matched_branches(), [0]);
+}
+
+#[test]
+fn match_mapped_alternatives_matched_branches() {
+    let alternatives = "int x"
+        .mapped_alternatives()
+        .longest()
+        .add_path(|rest| rest.match_static_mapped("in", true))
+        .add_path(|rest| rest.match_min_with_mapped(1, char::is_alphabetic, false));
+
+    assert_eq!(alternatives.matched_branches(), [0, 1]);
+
+    let (result, index) = alternatives.finalize_indexed();
+
+    assert_eq!(result.unwrap(), (Some(("int", false)), " x"));
+    assert_eq!(index, Some(1));
+}
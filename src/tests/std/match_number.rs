@@ -0,0 +1,71 @@
+use std::convert::TryFrom;
+
+use super::random::Random;
+use crate::{parser::Float, traits::*};
+
+fn digits(random: &mut Random, literal: &mut String, count: usize) {
+    for _ in 0..count {
+        literal.push(char::from(b'0' + u8::try_from(random.below(10)).unwrap()));
+    }
+}
+
+fn literal(random: &mut Random) -> String {
+    let mut literal: String = String::new();
+
+    if random.below(2) == 0 {
+        literal.push('-');
+    }
+
+    // Long runs of digits exercise inputs exceeding the significant digit limit.
+    let length: usize = if random.below(8) == 0 { 900 } else { 25 };
+
+    literal.push(char::from(b'1' + u8::try_from(random.below(9)).unwrap()));
+
+    let count: usize = random.below(length);
+
+    digits(random, &mut literal, count);
+
+    if random.below(2) == 0 {
+        literal.push('.');
+
+        let count: usize = 1 + random.below(length);
+
+        digits(random, &mut literal, count);
+    }
+
+    if random.below(2) == 0 {
+        let exponent: i64 = i64::try_from(random.below(700)).unwrap() - 350;
+
+        literal.push('e');
+        literal.push_str(&exponent.to_string());
+    }
+
+    literal
+}
+
+#[test]
+fn match_number_float_matches_std() {
+    let mut random: Random = Random::new(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..20_000 {
+        let literal: String = literal(&mut random);
+
+        let (matched, rest) = literal.as_str().match_number(Float::<f64>::json()).unwrap();
+        let (_, value) = matched.unwrap();
+
+        assert_eq!(rest, "");
+        assert_eq!(
+            value.to_bits(),
+            literal.parse::<f64>().unwrap().to_bits(),
+            "f64 from {literal}"
+        );
+
+        let (matched, _) = literal.as_str().match_number(Float::<f32>::json()).unwrap();
+
+        assert_eq!(
+            matched.unwrap().1.to_bits(),
+            literal.parse::<f32>().unwrap().to_bits(),
+            "f32 from {literal}"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{
+    parser::{Contents, Quoted},
+    traits::*,
+};
+
+#[test]
+fn match_string_decoded() {
+    let (value, rest) = r#""a\né😀\/\"" rest"#.match_string(Quoted::json()).unwrap();
+
+    assert_eq!(
+        value.unwrap().1,
+        Contents::Owned(String::from("a\né\u{1F600}/\""))
+    );
+    assert_eq!(rest, " rest");
+
+    let (value, _) = r#""\x41\u{1F600}\0\'\\""#.match_string(Quoted::rust()).unwrap();
+
+    assert_eq!(value.unwrap().1.decoded(), Some("A\u{1F600}\0'\\"));
+}
+
+#[test]
+fn match_string_decoded_bytes() {
+    let (value, _) = br#""\xFF\n\u{E9}""#.match_string(Quoted::rust()).unwrap();
+
+    assert_eq!(
+        value.unwrap().1,
+        Contents::Owned(vec![0xFF, b'\n', 0xC3, 0xA9])
+    );
+}
+
+#[test]
+fn match_string_custom_escapes() {
+    let format: Quoted<'_> = Quoted::new(b'\'')
+        .escape(b'^')
+        .table(&[(b'n', b'\n'), (b'e', 0xE9)]);
+
+    let (value, rest) = "'it^'s^n^^^e'!".match_string(format).unwrap();
+
+    assert_eq!(
+        value,
+        Some(("'it^'s^n^^^e'", Contents::Owned(String::from("it's\n^é"))))
+    );
+    assert_eq!(rest, "!");
+
+    let (value, _) = b"'^e'".match_string(format).unwrap();
+
+    assert_eq!(value.unwrap().1.decoded(), Some(&[0xE9][..]));
+}
+
+#[test]
+fn match_string_control_display() {
+    let error = "\"a\tb\"".match_string(Quoted::json()).take().unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "expected known escape sequence, found unescaped control character U+0009"
+    );
+}
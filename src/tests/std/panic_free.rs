@@ -0,0 +1,216 @@
+use std::{
+    convert::TryFrom,
+    ops::Bound,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use super::random::Random;
+use crate::{
+    input::{Located, Partial, Skipping, Spacing, Trivia},
+    parser::{Binary, Contents, Float, Integer, Quoted, Varint},
+    result::{MappedMatch, Match},
+    traits::*,
+};
+
+const ITERATIONS: u64 = 2000;
+
+/// Runs the passed function on random inputs, reporting the seed and the input that caused a panic.
+fn run<F>(f: F)
+where
+    F: Fn(&mut Random, &str),
+{
+    for seed in 1..=ITERATIONS {
+        let mut random: Random = Random::new(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+
+        let data: String = random.string(12);
+
+        assert!(
+            catch_unwind(AssertUnwindSafe(|| f(&mut random, &data))).is_ok(),
+            "panicked with seed {} on input {:?}",
+            seed,
+            data
+        );
+    }
+}
+
+fn pattern(random: &mut Random, data: &str) -> String {
+    if random.below(2) == 0 {
+        random.string(6)
+    } else {
+        let bytes: &[u8] = data.as_bytes();
+
+        let end: usize = random.offset(data).min(bytes.len());
+
+        String::from_utf8_lossy(&bytes[..end]).into_owned()
+    }
+}
+
+#[test]
+fn panic_free_match_static() {
+    run(|random, data| {
+        let pattern: String = pattern(random, data);
+
+        let _: Match<&str, &str> = data.match_static(&pattern);
+        let _: Match<&[u8], &[u8]> = data.as_bytes().match_static(pattern.as_bytes());
+        let _: Match<Located<&str>, Located<&str>> = Located::new(data).match_static(&pattern);
+        let _: Match<Partial<&str>, Partial<&str>> = Partial::new(data).match_static(&pattern);
+    });
+}
+
+#[test]
+fn panic_free_match_until() {
+    run(|random, data| {
+        let pattern: String = pattern(random, data);
+
+        let _: Match<&str, &str> = data.match_until(&pattern);
+        let _: Match<&str, &str> = data.match_until_including(&pattern);
+        let _: Match<&[u8], &[u8]> = data.as_bytes().match_until_found(pattern.as_bytes());
+        let _: Match<Located<&str>, Located<&str>> =
+            Located::new(data).match_until_including(&pattern);
+        let _: Match<Partial<&[u8]>, Partial<&[u8]>> =
+            Partial::new(data.as_bytes()).match_until(pattern.as_bytes());
+    });
+}
+
+#[test]
+fn panic_free_match_with() {
+    run(|random, data| {
+        let stop: char = random.char();
+
+        let _: Match<&str, &str> = data.match_with(|c: char| c != stop);
+        let _: Match<&str, &str> = data.match_with(|c: &char| c.is_alphabetic());
+        let _: Match<&[u8], &[u8]> = data.as_bytes().match_with(|byte: u8| byte < 0x80);
+        let _: Match<Located<&str>, Located<&str>> =
+            Located::new(data).match_with(|c: char| c != stop);
+        let _: Match<Partial<&str>, Partial<&str>> =
+            Partial::new(data).match_with(|c: char| c != stop);
+    });
+}
+
+#[test]
+fn panic_free_match_range_with() {
+    run(|random, data| {
+        let bounds: (Bound<usize>, Bound<usize>) = random.bounds();
+
+        let _: Match<&str, &str> = data.match_range_with(bounds, char::is_alphabetic);
+        let _: Match<&str, &str> = data.match_range_with(Bytes(bounds), |_: &char| true);
+        let _: Match<&[u8], &[u8]> = data.as_bytes().match_range_with(bounds, |_: u8| true);
+        let _: Match<Located<&str>, Located<&str>> =
+            Located::new(data).match_range_with(bounds, |_: char| true);
+        let _: Match<Partial<&str>, Partial<&str>> =
+            Partial::new(data).match_range_with(Bytes(bounds), |_: char| true);
+        let _: Match<Partial<&[u8]>, Partial<&[u8]>> =
+            Partial::new(data.as_bytes()).match_range_with(bounds, |_: &u8| true);
+    });
+}
+
+#[test]
+fn panic_free_match_static_ignore_case() {
+    run(|random, data| {
+        let pattern: String = pattern(random, data).to_uppercase();
+
+        let _: Match<&str, &str> = data.match_static_ignore_case(&pattern);
+        let _: Match<&[u8], &[u8]> = data.as_bytes().match_static_ignore_case(pattern.as_bytes());
+        let _: Match<Located<&str>, Located<&str>> =
+            Located::new(data).match_static_ignore_case(&pattern);
+        let _: Match<Partial<&str>, Partial<&str>> =
+            Partial::new(data).match_static_ignore_case(&pattern);
+    });
+}
+
+#[test]
+fn panic_free_match_number() {
+    const NUMERIC: &str = "0123456789abcdefxXoObB+-._eEpP";
+
+    run(|random, _| {
+        let data: String = random.text(NUMERIC, 48);
+        let data: &str = &data;
+        let radix: u32 = 2 + u32::try_from(random.below(35)).unwrap_or(0);
+
+        let _: MappedMatch<&str, &str, i8> = data.match_number(Integer::new());
+        let _: MappedMatch<&str, &str, u128> = data.match_number(
+            Integer::new()
+                .radix(radix)
+                .allow_prefixes()
+                .allow_separators(),
+        );
+        let _: MappedMatch<&[u8], &[u8], i64> = data
+            .as_bytes()
+            .match_number(Integer::new().radix(radix).forbid_sign());
+        let _: MappedMatch<&str, &str, f64> = data.match_number(Float::json());
+        let _: MappedMatch<&str, &str, f64> = data.match_number(Float::rust());
+        let _: MappedMatch<&[u8], &[u8], f32> = data.as_bytes().match_number(Float::c());
+    });
+}
+
+#[test]
+fn panic_free_match_string() {
+    const QUOTED: &str = "\"'\\^abnrtu{}0123456789ABCDEFx\n\u{1}";
+
+    run(|random, _| {
+        let data: String = random.text(QUOTED, 24);
+        let data: &str = &data;
+        let custom: Quoted<'_> = Quoted::new(b'\'')
+            .escape(b'^')
+            .table(&[(b'n', b'\n')])
+            .hexadecimal()
+            .braced_unicode();
+
+        let _: MappedMatch<&str, &str, Contents<'_, str>> = data.match_string(Quoted::json());
+        let _: MappedMatch<&str, &str, Contents<'_, str>> = data.match_string(Quoted::rust());
+        let _: MappedMatch<&str, &str, Contents<'_, str>> = data.match_string(custom);
+        let _: MappedMatch<&[u8], &[u8], Contents<'_, [u8]>> =
+            data.as_bytes().match_string(Quoted::json());
+        let _: MappedMatch<&[u8], &[u8], Contents<'_, [u8]>> =
+            data.as_bytes().match_string(custom.fixed_unicode());
+    });
+}
+
+#[test]
+fn panic_free_match_binary() {
+    run(|random, _| {
+        let data: Vec<u8> = random.bytes(12);
+        let data: &[u8] = &data;
+
+        let _: MappedMatch<&[u8], &[u8], u32> = data.match_binary(Binary::big());
+        let _: MappedMatch<&[u8], &[u8], i16> = data.match_binary(Binary::little());
+        let _: MappedMatch<&[u8], &[u8], f64> = data.match_binary(Binary::big());
+        let _: MappedMatch<&[u8], &[u8], u64> = data.match_binary(Varint::leb128());
+        let _: MappedMatch<&[u8], &[u8], i32> = data.match_binary(Varint::signed_leb128());
+        let _: MappedMatch<&[u8], &[u8], i8> = data.match_binary(Varint::zigzag());
+        let _: MappedMatch<&[u8], &[u8], u128> =
+            data.match_binary(Varint::leb128().allow_overlong());
+        let _: MappedMatch<Partial<&[u8]>, Partial<&[u8]>, u16> =
+            Partial::new(data).match_binary(Varint::leb128());
+    });
+}
+
+#[test]
+fn panic_free_skipping() {
+    const TRIVIA: &str = " \t\n/*#-{}[]a1\"";
+    const LINE: &[&str] = &["#", "--"];
+    const BLOCK: &[(&str, &str)] = &[("{-", "-}"), ("[", "]")];
+
+    run(|random, _| {
+        let data: String = random.text(TRIVIA, 24);
+        let data: &str = &data;
+        let custom: Trivia<'_> = Trivia::new()
+            .spacing(Spacing::Unicode)
+            .line_comments(LINE)
+            .block_comments(BLOCK)
+            .nested();
+
+        for trivia in [Trivia::c(), Trivia::rust(), custom] {
+            let _: Match<Skipping<'_, &str>, Skipping<'_, &str>> =
+                Skipping::new(data, trivia).match_trivia();
+            let _: Match<Skipping<'_, &str>, Skipping<'_, &str>> =
+                Skipping::new(data, trivia).match_static("a");
+            let _: Match<Skipping<'_, &[u8]>, Skipping<'_, &[u8]>> =
+                Skipping::new(data.as_bytes(), trivia).match_with(|byte: u8| byte == b'1');
+            let _: MappedMatch<Skipping<'_, &str>, Skipping<'_, &str>, u8> =
+                Skipping::new(data, trivia).match_number(Integer::new());
+            let _: MappedMatch<Skipping<'_, &str>, Skipping<'_, &str>, Contents<'_, str>> =
+                Skipping::new(data, trivia).match_string(Quoted::json());
+        }
+    });
+}
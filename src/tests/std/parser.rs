@@ -0,0 +1,23 @@
+use crate::{
+    parser::{on_collected_rest, Static},
+    result::Match,
+    traits::*,
+};
+
+fn pair(input: &str) -> Match<&str, &str> {
+    input.match_exact_with(2, |c: char| c.is_ascii_digit())
+}
+
+#[test]
+fn parser_collecting() {
+    let (matches, rest) = "#1234-"
+        .match_static("#")
+        .into_collecting()
+        .single(on_collected_rest(pair))
+        .single(on_collected_rest(pair))
+        .discarding(on_collected_rest(Static::new("-")))
+        .unwrap();
+
+    assert_eq!(matches, ["#", "12", "34"]);
+    assert!(rest.is_empty());
+}
@@ -0,0 +1,81 @@
+use std::{convert::TryFrom, ops::Bound};
+
+/// Small "xorshift" pseudo-random generator shared by the randomized tests.
+/// It keeps them reproducible and free of external crates.
+pub(super) struct Random(u64);
+
+impl Random {
+    /// Constructs a new generator from the passed seed, which must not be zero.
+    pub(super) const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub(super) fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub(super) fn below(&mut self, bound: usize) -> usize {
+        usize::try_from(self.next() % u64::try_from(bound).unwrap_or(u64::MAX)).unwrap_or(0)
+    }
+
+    pub(super) fn char(&mut self) -> char {
+        const POOL: &[char] = &[
+            'a', 'Z', '7', ' ', '#', 'я', 'Ж', 'é', '标', '识', '€', '😀', '\u{0}',
+        ];
+
+        if self.below(4) == 0 {
+            loop {
+                let code: u32 = u32::try_from(self.next() % 0x11_0000).unwrap_or(0);
+
+                if let Some(c) = core::char::from_u32(code) {
+                    return c;
+                }
+            }
+        } else {
+            POOL[self.below(POOL.len())]
+        }
+    }
+
+    pub(super) fn string(&mut self, maximum: usize) -> String {
+        (0..self.below(maximum + 1)).map(|_| self.char()).collect()
+    }
+
+    /// Returns a string made mostly of characters from the passed pool, so that it resembles a token.
+    pub(super) fn text(&mut self, pool: &str, maximum: usize) -> String {
+        let pool: Vec<char> = pool.chars().collect();
+
+        (0..self.below(maximum + 1))
+            .map(|_| {
+                if self.below(8) == 0 {
+                    self.char()
+                } else {
+                    pool[self.below(pool.len())]
+                }
+            })
+            .collect()
+    }
+
+    pub(super) fn bytes(&mut self, maximum: usize) -> Vec<u8> {
+        (0..self.below(maximum + 1))
+            .map(|_| u8::try_from(self.next() & 0xFF).unwrap_or(0))
+            .collect()
+    }
+
+    /// Returns a random byte offset, not necessarily on a character boundary.
+    pub(super) fn offset(&mut self, data: &str) -> usize {
+        self.below(data.len() + 2)
+    }
+
+    pub(super) fn bounds(&mut self) -> (Bound<usize>, Bound<usize>) {
+        let mut bound = || match self.below(3) {
+            0 => Bound::Included(self.below(8)),
+            1 => Bound::Excluded(self.below(8)),
+            _ => Bound::Unbounded,
+        };
+
+        (bound(), bound())
+    }
+}
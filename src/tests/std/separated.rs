@@ -0,0 +1,109 @@
+use crate::{
+    result::{CollectingMatch, Match, MatchFailed, Trailing},
+    traits::*,
+};
+
+fn number<'a>(_: Option<&&str>, rest: &'a str) -> Match<&'a str, &'a str> {
+    rest.match_min_with(1, |c: char| c.is_ascii_digit())
+}
+
+fn comma<'a>(_: Option<&&str>, rest: &'a str) -> Match<&'a str, &'a str> {
+    rest.match_static(",")
+}
+
+fn separated_test(data: &str, minimum: usize, trailing: Trailing) -> (Vec<&str>, &str) {
+    data.match_static("(")
+        .into_collecting()
+        .separated(minimum, trailing, comma, number)
+        .unwrap()
+}
+
+#[test]
+fn separated() {
+    assert_eq!(
+        separated_test("(1,23,4)", 0, Trailing::Forbidden),
+        (vec!["(", "1", "23", "4"], ")")
+    );
+    assert_eq!(
+        separated_test("()", 0, Trailing::Forbidden),
+        (vec!["("], ")")
+    );
+    assert_eq!(
+        separated_test("(1,)", 1, Trailing::Forbidden),
+        (vec!["(", "1"], ",)")
+    );
+    assert_eq!(
+        separated_test("(1,)", 1, Trailing::Allowed),
+        (vec!["(", "1"], ")")
+    );
+    assert_eq!(
+        separated_test("(1,2,)", 1, Trailing::Required),
+        (vec!["(", "1", "2"], ")")
+    );
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn separated_panic() {
+    let _ = separated_test("(1,2)", 3, Trailing::Forbidden);
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn separated_required_panic() {
+    let _ = separated_test("(1,2)", 0, Trailing::Required);
+}
+
+#[test]
+fn separated_into() {
+    let mut separators: Vec<&str> = Vec::new();
+
+    let (items, rest) = CollectingMatch::from("a / b // c")
+        .separated_into(
+            1,
+            Trailing::Forbidden,
+            &mut separators,
+            |_, rest: &str| {
+                rest.recognize(|rest: &str| {
+                    rest.match_max_with(1, |c: char| c == ' ')
+                        .match_min_with(1, |c: char| c == '/')
+                        .match_max_with(1, |c: char| c == ' ')
+                })
+            },
+            |_, rest: &str| rest.match_min_with(1, char::is_alphabetic),
+        )
+        .unwrap();
+
+    assert_eq!(items, ["a", "b", "c"]);
+    assert_eq!(separators, [" / ", " // "]);
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn separated_bytes() {
+    let (segments, rest) = CollectingMatch::from(&b"usr/local/bin "[..])
+        .separated(
+            1,
+            Trailing::Allowed,
+            |_, rest: &[u8]| rest.match_static(b"/"),
+            |_, rest: &[u8]| rest.match_min_with(1, |byte: u8| byte.is_ascii_alphabetic()),
+        )
+        .unwrap();
+
+    assert_eq!(segments, [&b"usr"[..], b"local", b"bin"]);
+    assert_eq!(rest, b" ");
+}
+
+#[test]
+fn separated_failed() {
+    let data: &str = "(1,x)";
+
+    let error: MatchFailed = data
+        .match_static("(")
+        .into_collecting()
+        .separated(2, Trailing::Forbidden, comma, number)
+        .finalize()
+        .unwrap_err();
+
+    assert_eq!(error.position(data.len()), Some(3));
+}
@@ -0,0 +1,195 @@
+use crate::{
+    input::{Located, Location, Partial, Skipping, Spacing, Trivia},
+    parser::{Integer, Quoted},
+    result::{Expected, Found, MatchFailed, Needed, Snippet},
+    traits::*,
+};
+
+fn trivia_test(data: &str) -> [&str; 3] {
+    let mut numbers: [&str; 3] = [""; 3];
+
+    let _ = Skipping::new(data, Trivia::c())
+        .match_static("#")
+        .match_exact_with(2, |c: char| c.is_ascii_digit())
+        .execute(|matched, _| numbers[0] = matched.unwrap().fragment())
+        .match_exact_with(2, |c: char| c.is_ascii_digit())
+        .execute(|matched, _| numbers[1] = matched.unwrap().fragment())
+        .match_exact_with(2, |c: char| c.is_ascii_digit())
+        .execute(|matched, _| numbers[2] = matched.unwrap().fragment())
+        .unwrap();
+
+    numbers
+}
+
+#[test]
+fn trivia() {
+    assert_eq!(trivia_test("#12 56 78"), ["12", "56", "78"]);
+    assert_eq!(
+        trivia_test("  # 12 /* first */ 56 // second\n\t78"),
+        ["12", "56", "78"]
+    );
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+fn trivia_panic() {
+    trivia_test("#12 56 # 78");
+}
+
+#[test]
+fn trivia_bytes() {
+    let (value, rest) = Skipping::new(&b" \r\n42 ,"[..], Trivia::new())
+        .match_number(Integer::<u8>::new())
+        .unwrap();
+
+    let (matched, value) = value.unwrap();
+
+    assert_eq!((matched.into_fragment(), value), (&b"42"[..], 42));
+    assert_eq!(rest.into_fragment(), b" ,");
+}
+
+#[test]
+fn trivia_nested() {
+    let data: &str = "/* a /* b */ c */ x";
+
+    assert_eq!(
+        Skipping::new(data, Trivia::rust())
+            .match_static("x")
+            .unwrap()
+            .1
+            .fragment(),
+        &""
+    );
+    assert_eq!(
+        Skipping::new(data, Trivia::c()).match_static("x").error(),
+        Some(&MatchFailed::new(
+            6,
+            Expected::Text(Snippet::new("x")),
+            Found::Text(Snippet::new("c"))
+        ))
+    );
+    assert_eq!(
+        Skipping::new(" /* a /* b */ x", Trivia::rust())
+            .match_static("x")
+            .error(),
+        Some(&MatchFailed::new(
+            14,
+            Expected::Text(Snippet::new("*/")),
+            Found::EndOfInput
+        ))
+    );
+}
+
+#[test]
+fn trivia_spacing() {
+    let horizontal: Trivia<'_> = Trivia::new()
+        .spacing(Spacing::Horizontal)
+        .line_comments(&["#", ";"]);
+
+    let (matched, rest) = Skipping::new(" \t# comment\n x", horizontal)
+        .match_static("\n")
+        .match_static("x")
+        .unwrap();
+
+    assert_eq!(matched.unwrap().fragment(), &"x");
+    assert_eq!(rest.fragment(), &"");
+
+    assert!(Skipping::new("\u{3000}x", Trivia::rust())
+        .match_static("x")
+        .rest()
+        .is_some());
+    assert!(Skipping::new("\u{3000}x", Trivia::c())
+        .match_static("x")
+        .is_failed());
+    assert!(Skipping::new(" x", Trivia::new().spacing(Spacing::None))
+        .match_static("x")
+        .is_failed());
+}
+
+#[test]
+fn trivia_verbatim() {
+    let (matched, rest) = Skipping::new("say \" a  b \" ;", Trivia::new())
+        .match_static("say")
+        .match_static("\"")
+        .transform_rest(Skipping::verbatim)
+        .match_with(|c: char| c != '"')
+        .execute(|matched, _| assert_eq!(matched.unwrap().fragment(), &" a  b "))
+        .match_static("\"")
+        .transform_rest(Skipping::skipping)
+        .match_static(";")
+        .unwrap();
+
+    assert_eq!(matched.unwrap().fragment(), &";");
+    assert!(!rest.is_verbatim());
+
+    let (value, _) = Skipping::new(" \" a  b \"", Trivia::new())
+        .match_string(Quoted::json())
+        .unwrap();
+
+    assert_eq!(value.unwrap().1.decoded(), Some(" a  b "));
+}
+
+#[test]
+fn trivia_match_trivia() {
+    let (matched, rest) = Skipping::new("x // end", Trivia::c())
+        .match_static("x")
+        .discarding(|_, rest: Skipping<'_, &str>| rest.match_trivia())
+        .unwrap();
+
+    assert_eq!(matched.unwrap().fragment(), &"x");
+    assert_eq!(rest.fragment(), &"");
+
+    let (matched, rest) = Skipping::new(" /**/ x", Trivia::c())
+        .match_trivia()
+        .unwrap();
+
+    assert_eq!(matched.unwrap().fragment(), &" /**/ ");
+    assert_eq!(rest.fragment(), &"x");
+}
+
+#[test]
+fn trivia_located() {
+    let input: Skipping<'_, Located<&str>> =
+        Skipping::new(Located::new("/* a */\n  foo bar"), Trivia::c());
+
+    let mut locations: [Location; 2] = [Location::default(); 2];
+
+    let (_, rest) = input
+        .match_static("foo")
+        .execute(|matched, _| locations[0] = matched.unwrap().fragment().location())
+        .match_static("bar")
+        .execute(|matched, _| locations[1] = matched.unwrap().fragment().location())
+        .unwrap();
+
+    assert_eq!(
+        locations,
+        [Location::new(10, 2, 3), Location::new(14, 2, 7)]
+    );
+    assert_eq!(rest.fragment().location(), Location::new(17, 2, 10));
+}
+
+#[test]
+fn trivia_partial() {
+    let error: MatchFailed = Skipping::new(Partial::new("  foo "), Trivia::c())
+        .match_static("foo")
+        .match_static("bar")
+        .take()
+        .unwrap_err();
+
+    assert!(error.is_incomplete());
+    assert_eq!(error.needed(), Some(Needed::Size(3)));
+
+    assert!(!Skipping::new(Partial::last("  foo "), Trivia::c())
+        .match_static("foo")
+        .match_static("bar")
+        .take()
+        .unwrap_err()
+        .is_incomplete());
+
+    let (matched, rest) = Skipping::new(Partial::new("  foo bar"), Trivia::c())
+        .match_static("foo")
+        .unwrap();
+
+    assert_eq!(matched.unwrap().fragment().fragment(), &"foo");
+    assert_eq!(rest.fragment().fragment(), &" bar");
+}
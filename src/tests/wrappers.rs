@@ -0,0 +1,303 @@
+#[cfg(feature = "std")]
+use crate::parser::Keywords;
+use crate::{
+    input::{Located, Location, Partial, Skipping, Trivia},
+    parser::{Binary, Integer, Quoted},
+    result::{Found, MatchFailed},
+    traits::*,
+};
+
+/// Location of the first token within the inputs of `skipping_located`, following a comment and a line break.
+const TOKEN: Location = Location::new(6, 2, 2);
+
+fn skipping_located(data: &str) -> Skipping<'_, Located<&str>> {
+    Skipping::new(Located::new(data), Trivia::c())
+}
+
+fn location<T>(matched: Option<Skipping<'_, Located<T>>>) -> Location {
+    matched.unwrap().fragment().location()
+}
+
+fn location_mapped<T, V>(matched: Option<(Skipping<'_, Located<T>>, V)>) -> (Location, V) {
+    let (matched, value) = matched.unwrap();
+
+    (matched.fragment().location(), value)
+}
+
+#[test]
+fn wrappers_skipping_located() {
+    let (matched, rest) = skipping_located("/**/\n a;").match_static("a").unwrap();
+
+    assert_eq!(location(matched), TOKEN);
+    assert_eq!(rest.fragment().location(), Location::new(7, 2, 3));
+
+    assert_eq!(
+        location(
+            skipping_located("/**/\n A")
+                .match_static_ignore_case("a")
+                .unwrap()
+                .0
+        ),
+        TOKEN
+    );
+    assert_eq!(
+        location(skipping_located("/**/\n ab;").match_until(";").unwrap().0),
+        TOKEN
+    );
+    assert_eq!(
+        location(
+            skipping_located("/**/\n ab;")
+                .match_with(|c: char| c.is_alphabetic())
+                .unwrap()
+                .0
+        ),
+        TOKEN
+    );
+    assert_eq!(
+        location(
+            skipping_located("/**/\n ab;")
+                .match_exact_with(2, |c: char| c.is_alphabetic())
+                .unwrap()
+                .0
+        ),
+        TOKEN
+    );
+
+    let (matched, _) = skipping_located("/**/\n ab;")
+        .recognize(|input: Skipping<'_, Located<&str>>| input.match_static("a").match_static("b"))
+        .unwrap();
+
+    assert_eq!(matched.unwrap().fragment().fragment(), &"ab");
+    assert_eq!(location(matched), TOKEN);
+
+    assert_eq!(
+        location_mapped(
+            skipping_located("/**/\n 42;")
+                .match_number(Integer::<u8>::new())
+                .unwrap()
+                .0
+        ),
+        (TOKEN, 42)
+    );
+    assert_eq!(
+        location_mapped(
+            skipping_located("/**/\n \"s\";")
+                .match_string(Quoted::json())
+                .unwrap()
+                .0
+        )
+        .0,
+        TOKEN
+    );
+
+    #[cfg(feature = "std")]
+    assert_eq!(
+        location_mapped(
+            skipping_located("/**/\n set;")
+                .match_keywords(&Keywords::indexed(["select", "set"]))
+                .unwrap()
+                .0
+        ),
+        (TOKEN, 1)
+    );
+
+    let (matched, _) = Skipping::new(Located::new(&b" \x12\x34"[..]), Trivia::new())
+        .match_binary(Binary::<u16>::big())
+        .unwrap();
+
+    assert_eq!(location_mapped(matched), (Location::new(1, 1, 2), 0x1234));
+}
+
+fn skipping_partial(data: &str) -> Skipping<'_, Partial<&str>> {
+    Skipping::new(Partial::new(data), Trivia::c())
+}
+
+fn is_incomplete<T>(result: Result<T, MatchFailed>) -> bool {
+    result.err().is_some_and(|error| error.is_incomplete())
+}
+
+#[test]
+fn wrappers_skipping_partial() {
+    let (matched, rest) = skipping_partial(" a ").match_static("a").unwrap();
+
+    assert_eq!(matched.unwrap().fragment().fragment(), &"a");
+    assert!(!rest.fragment().is_last());
+
+    assert!(is_incomplete(
+        skipping_partial(" fo").match_static("foo").take()
+    ));
+    assert!(is_incomplete(
+        skipping_partial(" FO")
+            .match_static_ignore_case("foo")
+            .take()
+    ));
+    assert!(is_incomplete(
+        skipping_partial(" ab").match_until(";").take()
+    ));
+    assert!(is_incomplete(
+        skipping_partial(" ab")
+            .match_with(|c: char| c.is_alphabetic())
+            .take()
+    ));
+    assert!(is_incomplete(
+        skipping_partial(" ab")
+            .match_min_with(1, |c: char| c.is_alphabetic())
+            .take()
+    ));
+    assert!(is_incomplete(
+        skipping_partial(" a")
+            .recognize(|input: Skipping<'_, Partial<&str>>| input
+                .match_static("a")
+                .match_static("b"))
+            .take()
+    ));
+    assert!(is_incomplete(
+        skipping_partial(" 42")
+            .match_number(Integer::<u8>::new())
+            .take()
+    ));
+    assert!(is_incomplete(
+        skipping_partial(" \"ab")
+            .match_string(Quoted::json())
+            .take()
+    ));
+    assert!(is_incomplete(
+        Skipping::new(Partial::new(&b" \x12"[..]), Trivia::new())
+            .match_binary(Binary::<u16>::big())
+            .take()
+    ));
+
+    #[cfg(feature = "std")]
+    assert!(is_incomplete(
+        skipping_partial(" sel")
+            .match_keywords(&Keywords::indexed(["select", "set"]))
+            .take()
+    ));
+
+    let (value, _) = skipping_partial(" 42;")
+        .match_number(Integer::<u8>::new())
+        .unwrap();
+
+    assert_eq!(value.unwrap().1, 42);
+}
+
+#[test]
+fn wrappers_skipping_partial_comment() {
+    assert!(is_incomplete(
+        skipping_partial(" /* a").match_static("b").take()
+    ));
+
+    let error: MatchFailed = Skipping::new(Partial::last(" /* a"), Trivia::c())
+        .match_static("b")
+        .take()
+        .unwrap_err();
+
+    assert!(!error.is_incomplete());
+    assert_eq!(error.found(), &Found::EndOfInput);
+}
+
+fn located_partial(data: &str) -> Located<Partial<&str>> {
+    Located::new(Partial::new(data))
+}
+
+#[test]
+fn wrappers_located_partial() {
+    let (matched, rest) = located_partial("a\nb;").match_static("a\n").unwrap();
+
+    assert_eq!(matched.unwrap().location(), Location::new(0, 1, 1));
+    assert_eq!(rest.location(), Location::new(2, 2, 1));
+
+    let (_, rest) = located_partial("Ab;")
+        .match_static_ignore_case("a")
+        .match_until(";")
+        .unwrap();
+
+    assert_eq!(rest.location(), Location::new(2, 1, 3));
+
+    let (matched, _) = located_partial("ab;")
+        .recognize(|input: Located<Partial<&str>>| {
+            input
+                .match_with(|c: char| c == 'a')
+                .match_exact_with(1, |c: char| c == 'b')
+        })
+        .unwrap();
+
+    assert_eq!(matched.unwrap().fragment().fragment(), &"ab");
+
+    let (value, rest) = located_partial("42;")
+        .match_number(Integer::<u8>::new())
+        .unwrap();
+
+    assert_eq!(value.unwrap().1, 42);
+    assert_eq!(rest.location(), Location::new(2, 1, 3));
+
+    let (value, rest) = located_partial("\"s\";")
+        .match_string(Quoted::json())
+        .unwrap();
+
+    assert_eq!(value.unwrap().0.location(), Location::new(0, 1, 1));
+    assert_eq!(rest.location(), Location::new(3, 1, 4));
+
+    let (value, rest) = Located::new(Partial::new(&[0x12, 0x34, 0x56][..]))
+        .match_binary(Binary::<u16>::big())
+        .unwrap();
+
+    assert_eq!(value.unwrap().1, 0x1234);
+    assert_eq!(rest.location(), Location::new(2, 1, 3));
+
+    #[cfg(feature = "std")]
+    {
+        let (value, rest) = located_partial("set;")
+            .match_keywords(&Keywords::indexed(["select", "set"]))
+            .unwrap();
+
+        assert_eq!(value.unwrap().1, 1);
+        assert_eq!(rest.location(), Location::new(3, 1, 4));
+    }
+}
+
+#[test]
+fn wrappers_located_partial_incomplete() {
+    assert!(is_incomplete(
+        located_partial("fo").match_static("foo").take()
+    ));
+    assert!(is_incomplete(
+        located_partial("FO").match_static_ignore_case("foo").take()
+    ));
+    assert!(is_incomplete(located_partial("ab").match_until(";").take()));
+    assert!(is_incomplete(
+        located_partial("ab")
+            .match_with(|c: char| c.is_alphabetic())
+            .take()
+    ));
+    assert!(is_incomplete(
+        located_partial("ab")
+            .match_min_with(1, |c: char| c.is_alphabetic())
+            .take()
+    ));
+    assert!(is_incomplete(
+        located_partial("a")
+            .recognize(|input: Located<Partial<&str>>| input.match_static("a").match_static("b"))
+            .take()
+    ));
+    assert!(is_incomplete(
+        located_partial("42")
+            .match_number(Integer::<u8>::new())
+            .take()
+    ));
+    assert!(is_incomplete(
+        located_partial("\"ab").match_string(Quoted::json()).take()
+    ));
+    assert!(is_incomplete(
+        Located::new(Partial::new(&[0x12][..]))
+            .match_binary(Binary::<u16>::big())
+            .take()
+    ));
+
+    #[cfg(feature = "std")]
+    assert!(is_incomplete(
+        located_partial("sel")
+            .match_keywords(&Keywords::indexed(["select", "set"]))
+            .take()
+    ));
+}
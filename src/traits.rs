@@ -6,15 +6,41 @@ pub use alternatives::*;
 mod into_match;
 pub use into_match::*;
 
+mod match_binary;
+pub use match_binary::*;
+
 mod match_fail;
 pub use match_fail::*;
 
+#[cfg(feature = "std")]
+mod match_keywords;
+#[cfg(feature = "std")]
+pub use match_keywords::*;
+
+mod match_number;
+pub use match_number::*;
+
 mod match_static;
 pub use match_static::*;
 
+mod match_static_ignore_case;
+pub use match_static_ignore_case::*;
+
+mod match_static_ignore_case_mapped;
+pub use match_static_ignore_case_mapped::*;
+
 mod match_static_mapped;
 pub use match_static_mapped::*;
 
+mod match_string;
+pub use match_string::*;
+
+mod match_until;
+pub use match_until::*;
+
+mod match_until_mapped;
+pub use match_until_mapped::*;
+
 mod match_with;
 pub use match_with::*;
 
@@ -26,3 +52,9 @@ pub use match_with_in_range::*;
 
 mod match_with_in_range_mapped;
 pub use match_with_in_range_mapped::*;
+
+mod recognize;
+pub use recognize::*;
+
+mod sequence;
+pub use sequence::*;
@@ -11,6 +11,16 @@ pub trait Alternatives: Sized {
     fn mapped_alternatives<T, U, V>(self) -> MappedAlternativesMatch<Self, T, U, V> {
         MappedAlternativesMatch::new(self)
     }
+
+    /// Creates a new alternatives tree, which identifies branches with tags of the given type.
+    fn tagged_alternatives<T, U, X>(self) -> AlternativesMatch<Self, T, U, X> {
+        AlternativesMatch::new(self)
+    }
+
+    /// Creates a new mapped alternatives tree, which identifies branches with tags of the given type.
+    fn tagged_mapped_alternatives<T, U, V, X>(self) -> MappedAlternativesMatch<Self, T, U, V, X> {
+        MappedAlternativesMatch::new(self)
+    }
 }
 
 impl<T> Alternatives for T {}
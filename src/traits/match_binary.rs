@@ -0,0 +1,52 @@
+use crate::{
+    parser::{Binary, Fixed, Variable, Varint},
+    result::{Expected, Found, MappedMatch, Match, MatchFailed},
+    traits::MatchFail,
+};
+
+/// Provides interface for matching binary values, mapping the "matched" part to their values.
+/// The value's format determines both its type and its encoding: fixed-width values in either byte order,
+/// like `Binary::<u32>::big()`, or variable-length integers, like `Varint::<u64>::leb128()`.
+/// See [`Binary`] and [`Varint`].
+///
+/// [`Binary`]: ../parser/struct.Binary.html
+/// [`Varint`]: ../parser/struct.Varint.html
+pub trait MatchBinary<F, R>: Sized {
+    /// Matches a binary value of the passed format.
+    fn match_binary(self, format: F) -> R;
+}
+
+impl<N> MatchBinary<Binary<N>, MappedMatch<Self, Self, N>> for &[u8]
+where
+    N: Fixed,
+{
+    fn match_binary(self, format: Binary<N>) -> MappedMatch<Self, Self, N> {
+        if let Some(value) = N::read(self, format.endian()) {
+            let (matched, rest): (Self, Self) = self.split_at(N::SIZE);
+
+            Match::new(Some(matched), rest).map(value)
+        } else {
+            MappedMatch::failed_with(MatchFailed::new(
+                self.len(),
+                Expected::Elements(N::SIZE),
+                Found::EndOfInput,
+            ))
+        }
+    }
+}
+
+impl<N> MatchBinary<Varint<N>, MappedMatch<Self, Self, N>> for &[u8]
+where
+    N: Variable,
+{
+    fn match_binary(self, format: Varint<N>) -> MappedMatch<Self, Self, N> {
+        match format.read(self) {
+            Ok((length, value)) => {
+                let (matched, rest): (Self, Self) = self.split_at(length);
+
+                Match::new(Some(matched), rest).map(value)
+            }
+            Err(error) => MappedMatch::failed_with(error),
+        }
+    }
+}
@@ -1,5 +1,17 @@
+use crate::result::MatchFailed;
+
 /// Builder for failing matching results.
 pub trait MatchFail: Sized {
     /// Constructs a new "failed" instance.
     fn failed() -> Self;
+
+    /// Constructs a new "failed" instance that carries the passed failure description.
+    /// ## Notes
+    /// The default implementation discards the description and falls back to the `failed` method.
+    #[must_use]
+    fn failed_with(error: MatchFailed) -> Self {
+        let _ = error;
+
+        Self::failed()
+    }
 }
@@ -0,0 +1,93 @@
+use crate::{
+    parser::{Keywords, Search},
+    result::{Expected, Found, MappedMatch, Match, MatchFailed, Snippet},
+    traits::MatchFail,
+};
+
+/// Provides interface for matching the longest of many "static" patterns at once.
+/// The "matched" part is mapped to the value associated with the matched keyword.
+/// When no keyword matches, the failure expects the shortest keyword that continues the longest walked prefix.
+/// See [`Keywords`].
+///
+/// [`Keywords`]: ../parser/struct.Keywords.html
+pub trait MatchKeywords<K, R>: Sized {
+    /// Matches the longest keyword of the set.
+    fn match_keywords(self, keywords: K) -> R;
+}
+
+impl<'a, E, V> MatchKeywords<&'a Keywords<E, V>, MappedMatch<Self, Self, V>> for &[E]
+where
+    E: Eq,
+    V: Clone,
+{
+    fn match_keywords(self, keywords: &'a Keywords<E, V>) -> MappedMatch<Self, Self, V> {
+        let search: Search<'_, V> = keywords.search(self, |_| true);
+
+        if let Some((length, value)) = search.longest {
+            let (matched, rest): (Self, Self) = self.split_at(length);
+
+            Match::new(Some(matched), rest).map(value.clone())
+        } else {
+            let expected: Expected = keywords
+                .completion(search.node)
+                .map_or(Expected::Unknown, |completion| {
+                    Expected::Elements(search.depth + completion.len())
+                });
+
+            MappedMatch::failed_with(MatchFailed::new(
+                self.len(),
+                expected,
+                if search.exhausted {
+                    Found::EndOfInput
+                } else {
+                    Found::Mismatch(search.depth)
+                },
+            ))
+        }
+    }
+}
+
+impl<'a, V> MatchKeywords<&'a Keywords<u8, V>, MappedMatch<Self, Self, V>> for &str
+where
+    V: Clone,
+{
+    fn match_keywords(self, keywords: &'a Keywords<u8, V>) -> MappedMatch<Self, Self, V> {
+        let search: Search<'_, V> =
+            keywords.search(self.as_bytes(), |length| self.is_char_boundary(length));
+
+        if let Some((length, value)) = search.longest {
+            let (matched, rest): (Self, Self) = self.split_at(length);
+
+            Match::new(Some(matched), rest).map(value.clone())
+        } else {
+            // Describes the shortest keyword that continues the walked part of the input.
+            let expected: Expected =
+                keywords
+                    .completion(search.node)
+                    .map_or(Expected::Unknown, |completion| {
+                        let keyword: Vec<u8> = self.as_bytes()[..search.depth]
+                            .iter()
+                            .chain(completion)
+                            .copied()
+                            .collect();
+
+                        Expected::Text(Snippet::new(&String::from_utf8_lossy(&keyword)))
+                    });
+
+            MappedMatch::failed_with(MatchFailed::new(
+                self.len(),
+                expected,
+                if search.exhausted {
+                    Found::EndOfInput
+                } else {
+                    // Covers the walked part of the input, including the character it stopped at.
+                    let end: usize = (search.depth + 1..=self.len())
+                        .find(|&end| self.is_char_boundary(end))
+                        .unwrap_or(self.len());
+
+                    Found::Text(Snippet::new(self.get(..end).unwrap_or_default()))
+                },
+            ))
+        }
+    }
+}
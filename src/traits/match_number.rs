@@ -0,0 +1,57 @@
+use crate::{
+    parser::Format,
+    result::{Found, MappedMatch, Match, Snippet},
+    traits::MatchFail,
+};
+
+/// Provides interface for matching numeric literals, mapping the "matched" part to their values.
+/// The literal's format, like [`Integer`] or [`Float`], also determines the type of the value.
+///
+/// [`Integer`]: ../parser/struct.Integer.html
+/// [`Float`]: ../parser/struct.Float.html
+pub trait MatchNumber<F, R>: Sized {
+    /// Matches a numeric literal of the passed format.
+    fn match_number(self, format: F) -> R;
+}
+
+impl<F, N> MatchNumber<F, MappedMatch<Self, Self, N>> for &[u8]
+where
+    F: Format<Value = N>,
+{
+    fn match_number(self, format: F) -> MappedMatch<Self, Self, N> {
+        match format.read(self) {
+            Ok((length, value)) => {
+                let (matched, rest): (Self, Self) = self.split_at(length);
+
+                Match::new(Some(matched), rest).map(value)
+            }
+            Err(error) => {
+                MappedMatch::failed_with(error.into_failure(self.len(), |_| Found::Mismatch(0)))
+            }
+        }
+    }
+}
+
+impl<F, N> MatchNumber<F, MappedMatch<Self, Self, N>> for &str
+where
+    F: Format<Value = N>,
+{
+    fn match_number(self, format: F) -> MappedMatch<Self, Self, N> {
+        match format.read(self.as_bytes()) {
+            // The literal consists of ASCII characters only, so it ends on a character boundary.
+            Ok((length, value)) => {
+                let (matched, rest): (Self, Self) = self.split_at(length);
+
+                Match::new(Some(matched), rest).map(value)
+            }
+            Err(error) => MappedMatch::failed_with(error.into_failure(self.len(), |offset| {
+                let found: &str = self
+                    .get(offset..)
+                    .and_then(|rest| rest.chars().next().map(|c| &rest[..c.len_utf8()]))
+                    .unwrap_or_default();
+
+                Found::Text(Snippet::new(found))
+            })),
+        }
+    }
+}
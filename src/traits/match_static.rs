@@ -1,7 +1,13 @@
-use crate::{result::Match, traits::MatchFail};
+use crate::{
+    result::{Expected, Found, Match, MatchFailed, Snippet},
+    traits::MatchFail,
+};
 
 /// Provides interface for matching single "static" pattern.
 /// "Static" in this case is rather "not dynamic" (not changing) during the call, than constant.
+/// ## Notes
+/// The implementations for `&[E]` and `&str` never panic, regardless of the input and the pattern.
+/// In particular, a `&str` pattern that ends inside a multi-byte character of the input fails the match.
 pub trait MatchStatic<E, T, R>: Sized {
     /// Matches a "static" pattern.
     fn match_static(self, pattern: T) -> R;
@@ -15,16 +21,20 @@ where
     fn match_static(self, pattern: T) -> Match<Self, Self> {
         let pattern: &[U] = pattern.as_ref();
 
-        if pattern.is_empty() {
-            return Match::<Self, Self>::new(Some(&self[..0]), &self);
-        }
-
-        let len: usize = self.len().min(pattern.len());
+        let (matched, rest): (Self, Self) = self.split_at(self.len().min(pattern.len()));
 
-        if &self[..len] == pattern {
-            Match::new(Some(&self[..len]), &self[len..])
+        if matched == pattern {
+            Match::new(Some(matched), rest)
         } else {
-            Match::failed()
+            Match::failed_with(MatchFailed::new(
+                self.len(),
+                Expected::Elements(pattern.len()),
+                matched
+                    .iter()
+                    .zip(pattern)
+                    .position(|(element, expected)| element != expected)
+                    .map_or(Found::EndOfInput, Found::Mismatch),
+            ))
         }
     }
 }
@@ -36,12 +46,26 @@ where
     fn match_static(self, pattern: T) -> Match<Self, Self> {
         let pattern: &str = pattern.as_ref();
 
-        let len: usize = self.len().min(pattern.len());
-
-        if &self[..len] == pattern {
-            Match::new(Some(&self[..len]), &self[len..])
-        } else {
-            Match::failed()
+        if let (Some(matched), Some(rest)) = (self.get(..pattern.len()), self.get(pattern.len()..))
+        {
+            if matched == pattern {
+                return Match::new(Some(matched), rest);
+            }
         }
+
+        Match::failed_with(MatchFailed::new(
+            self.len(),
+            Expected::Text(Snippet::new(pattern)),
+            if pattern.starts_with(self) {
+                Found::EndOfInput
+            } else {
+                // Covers the input overlapping with the pattern, including a character that crosses its end.
+                let end: usize = (pattern.len().min(self.len())..=self.len())
+                    .find(|&end| self.is_char_boundary(end))
+                    .unwrap_or(self.len());
+
+                Found::Text(Snippet::new(self.get(..end).unwrap_or_default()))
+            },
+        ))
     }
 }
@@ -0,0 +1,82 @@
+use crate::{
+    result::{Expected, Found, Match, MatchFailed, Snippet},
+    traits::MatchFail,
+};
+
+/// Provides interface for matching single "static" pattern, without regard to case.
+/// This is a counter part of [`MatchStatic`], the "matched" part keeps the case of the input.
+/// ## Notes
+/// The implementation for `&[u8]` compares ASCII letters only, while the one for `&str` compares
+/// characters by their lower and upper case mappings, which covers the simple case folding.
+/// Neither implementation panics, regardless of the input and the pattern.
+///
+/// [`MatchStatic`]: trait.MatchStatic.html
+pub trait MatchStaticIgnoreCase<E, T, R>: Sized {
+    /// Matches a "static" pattern, without regard to case.
+    fn match_static_ignore_case(self, pattern: T) -> R;
+}
+
+impl<T> MatchStaticIgnoreCase<u8, T, Match<Self, Self>> for &[u8]
+where
+    T: AsRef<[u8]>,
+{
+    fn match_static_ignore_case(self, pattern: T) -> Match<Self, Self> {
+        let pattern: &[u8] = pattern.as_ref();
+
+        let (matched, rest): (Self, Self) = self.split_at(self.len().min(pattern.len()));
+
+        if matched.eq_ignore_ascii_case(pattern) {
+            Match::new(Some(matched), rest)
+        } else {
+            Match::failed_with(MatchFailed::new(
+                self.len(),
+                Expected::Elements(pattern.len()),
+                matched
+                    .iter()
+                    .zip(pattern)
+                    .position(|(byte, expected)| !byte.eq_ignore_ascii_case(expected))
+                    .map_or(Found::EndOfInput, Found::Mismatch),
+            ))
+        }
+    }
+}
+
+impl<T> MatchStaticIgnoreCase<char, T, Match<Self, Self>> for &str
+where
+    T: AsRef<str>,
+{
+    fn match_static_ignore_case(self, pattern: T) -> Match<Self, Self> {
+        let pattern: &str = pattern.as_ref();
+
+        let mut chars = self.char_indices();
+        let mut end: usize = 0;
+
+        for expected in pattern.chars() {
+            match chars.next() {
+                Some((index, c)) if equal_ignoring_case(c, expected) => end = index + c.len_utf8(),
+                found => {
+                    return Match::failed_with(MatchFailed::new(
+                        self.len(),
+                        Expected::Text(Snippet::new(pattern)),
+                        found.map_or(Found::EndOfInput, |(index, c)| {
+                            Found::Text(Snippet::new(
+                                self.get(..index + c.len_utf8()).unwrap_or_default(),
+                            ))
+                        }),
+                    ));
+                }
+            }
+        }
+
+        // The end always lies on a character boundary, as it is taken from the input's characters.
+        let (matched, rest): (Self, Self) = self.split_at(end);
+
+        Match::new(Some(matched), rest)
+    }
+}
+
+fn equal_ignoring_case(c: char, expected: char) -> bool {
+    c == expected
+        || c.to_lowercase().eq(expected.to_lowercase())
+        || c.to_uppercase().eq(expected.to_uppercase())
+}
@@ -0,0 +1,22 @@
+use crate::{
+    result::{MappedMatch, Match},
+    traits::MatchStaticIgnoreCase,
+};
+
+/// Provides interface for matching single "static" pattern, without regard to case.
+/// This is a counter part of [`MatchStaticIgnoreCase`], mapping the "matched" part to the passed value.
+///
+/// [`MatchStaticIgnoreCase`]: trait.MatchStaticIgnoreCase.html
+pub trait MatchStaticIgnoreCaseMapped<E, T, R, M>: Sized {
+    /// Matches a "static" pattern, without regard to case.
+    fn match_static_ignore_case_mapped(self, pattern: T, value: M) -> R;
+}
+
+impl<E, T, M, R, Q, I> MatchStaticIgnoreCaseMapped<E, T, MappedMatch<M, R, Q>, Q> for I
+where
+    Self: MatchStaticIgnoreCase<E, T, Match<M, R>>,
+{
+    fn match_static_ignore_case_mapped(self, pattern: T, value: Q) -> MappedMatch<M, R, Q> {
+        self.match_static_ignore_case(pattern).map(value)
+    }
+}
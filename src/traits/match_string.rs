@@ -0,0 +1,55 @@
+use crate::{
+    parser::{Contents, Quoted},
+    result::{Found, MappedMatch, Match, Snippet},
+    traits::MatchFail,
+};
+
+/// Provides interface for matching quoted string literals, mapping the "matched" part to their contents.
+/// The literal's format, like [`Quoted::json`] or [`Quoted::rust`], determines the quote and the escape sequences.
+/// ## Notes
+/// The "matched" part includes the quotes, while the contents are borrowed from the input, unless they
+/// had to be decoded. See [`Contents`].
+///
+/// [`Quoted::json`]: ../parser/struct.Quoted.html#method.json
+/// [`Quoted::rust`]: ../parser/struct.Quoted.html#method.rust
+/// [`Contents`]: ../parser/enum.Contents.html
+pub trait MatchString<F, R>: Sized {
+    /// Matches a string literal of the passed format.
+    fn match_string(self, format: F) -> R;
+}
+
+impl<'a, 'b> MatchString<Quoted<'b>, MappedMatch<Self, Self, Contents<'a, [u8]>>> for &'a [u8] {
+    fn match_string(self, format: Quoted<'b>) -> MappedMatch<Self, Self, Contents<'a, [u8]>> {
+        match format.read(self) {
+            Ok((length, contents)) => {
+                let (matched, rest): (Self, Self) = self.split_at(length);
+
+                Match::new(Some(matched), rest).map(contents)
+            }
+            Err(error) => {
+                MappedMatch::failed_with(error.into_failure(self.len(), |_| Found::Mismatch(0)))
+            }
+        }
+    }
+}
+
+impl<'a, 'b> MatchString<Quoted<'b>, MappedMatch<Self, Self, Contents<'a, str>>> for &'a str {
+    fn match_string(self, format: Quoted<'b>) -> MappedMatch<Self, Self, Contents<'a, str>> {
+        match format.read(self) {
+            // The literal ends with an ASCII quote, so it ends on a character boundary.
+            Ok((length, contents)) => {
+                let (matched, rest): (Self, Self) = self.split_at(length);
+
+                Match::new(Some(matched), rest).map(contents)
+            }
+            Err(error) => MappedMatch::failed_with(error.into_failure(self.len(), |offset| {
+                let found: &str = self
+                    .get(offset..)
+                    .and_then(|rest| rest.chars().next().map(|c| &rest[..c.len_utf8()]))
+                    .unwrap_or_default();
+
+                Found::Text(Snippet::new(found))
+            })),
+        }
+    }
+}
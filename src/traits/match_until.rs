@@ -0,0 +1,214 @@
+use core::cmp::Ordering;
+
+use crate::{
+    result::{Expected, Found, Match, MatchFailed, Snippet},
+    traits::MatchFail,
+};
+
+/// Provides interface for matching everything up to a single "static" pattern.
+/// "Static" in this case is rather "not dynamic" (not changing) during the call, than constant.
+/// ## Notes
+/// The pattern is searched for in linear time: `&str` uses the standard library's substring search,
+/// while `&[E]` uses the "Two-Way" algorithm, which needs no allocation and never restarts the search.
+/// An empty pattern is found at the very start of the input.
+pub trait MatchUntil<E, T, R>: Sized {
+    /// Matches everything before the first occurrence of the pattern, leaving the pattern in the rest.
+    /// When the pattern doesn't occur, the whole input is matched.
+    fn match_until(self, pattern: T) -> R;
+
+    /// Matches everything up to and including the first occurrence of the pattern.
+    /// Fails when the pattern doesn't occur.
+    fn match_until_including(self, pattern: T) -> R;
+
+    /// Matches everything before the first occurrence of the pattern, leaving the pattern in the rest.
+    /// Fails when the pattern doesn't occur.
+    fn match_until_found(self, pattern: T) -> R;
+}
+
+/// Describes which part of the input is consumed once the pattern is found.
+#[derive(Clone, Copy)]
+enum Until {
+    Exclusive,
+    Inclusive,
+    Found,
+}
+
+impl<E, T, U> MatchUntil<U, T, Match<Self, Self>> for &[E]
+where
+    E: PartialEq<U>,
+    T: AsRef<[U]>,
+    U: Ord,
+{
+    fn match_until(self, pattern: T) -> Match<Self, Self> {
+        until_in_slice(self, pattern.as_ref(), Until::Exclusive)
+    }
+
+    fn match_until_including(self, pattern: T) -> Match<Self, Self> {
+        until_in_slice(self, pattern.as_ref(), Until::Inclusive)
+    }
+
+    fn match_until_found(self, pattern: T) -> Match<Self, Self> {
+        until_in_slice(self, pattern.as_ref(), Until::Found)
+    }
+}
+
+impl<T> MatchUntil<char, T, Match<Self, Self>> for &str
+where
+    T: AsRef<str>,
+{
+    fn match_until(self, pattern: T) -> Match<Self, Self> {
+        until_in_str(self, pattern.as_ref(), Until::Exclusive)
+    }
+
+    fn match_until_including(self, pattern: T) -> Match<Self, Self> {
+        until_in_str(self, pattern.as_ref(), Until::Inclusive)
+    }
+
+    fn match_until_found(self, pattern: T) -> Match<Self, Self> {
+        until_in_str(self, pattern.as_ref(), Until::Found)
+    }
+}
+
+fn until_in_slice<'a, E, U>(input: &'a [E], pattern: &[U], until: Until) -> Match<&'a [E], &'a [E]>
+where
+    E: PartialEq<U>,
+    U: Ord,
+{
+    let end: Option<usize> = match (find(input, pattern), until) {
+        (Some(position), Until::Inclusive) => Some(position + pattern.len()),
+        (Some(position), _) => Some(position),
+        (None, Until::Exclusive) => Some(input.len()),
+        (None, _) => None,
+    };
+
+    if let Some(end) = end {
+        let (matched, rest): (&[E], &[E]) = input.split_at(end);
+
+        Match::new(Some(matched), rest)
+    } else {
+        Match::failed_with(MatchFailed::new(
+            0,
+            Expected::Elements(pattern.len()),
+            Found::EndOfInput,
+        ))
+    }
+}
+
+fn until_in_str<'a>(input: &'a str, pattern: &str, until: Until) -> Match<&'a str, &'a str> {
+    let end: Option<usize> = match (input.find(pattern), until) {
+        (Some(position), Until::Inclusive) => Some(position + pattern.len()),
+        (Some(position), _) => Some(position),
+        (None, Until::Exclusive) => Some(input.len()),
+        (None, _) => None,
+    };
+
+    if let (Some(matched), Some(rest)) = (
+        end.and_then(|end| input.get(..end)),
+        end.and_then(|end| input.get(end..)),
+    ) {
+        Match::new(Some(matched), rest)
+    } else {
+        Match::failed_with(MatchFailed::new(
+            0,
+            Expected::Text(Snippet::new(pattern)),
+            Found::EndOfInput,
+        ))
+    }
+}
+
+/// Returns the start of the maximal suffix of the pattern and its period, under the given ordering.
+fn maximal_suffix<U>(pattern: &[U], order: Ordering) -> (usize, usize)
+where
+    U: Ord,
+{
+    let mut left: usize = 0;
+    let mut right: usize = 1;
+    let mut offset: usize = 0;
+    let mut period: usize = 1;
+
+    while let (Some(a), Some(b)) = (pattern.get(right + offset), pattern.get(left + offset)) {
+        match a.cmp(b) {
+            Ordering::Equal => {
+                if offset + 1 == period {
+                    right += period;
+                    offset = 0;
+                } else {
+                    offset += 1;
+                }
+            }
+            ordering if ordering == order => {
+                right += offset + 1;
+                offset = 0;
+                period = right - left;
+            }
+            _ => {
+                left = right;
+                right += 1;
+                offset = 0;
+                period = 1;
+            }
+        }
+    }
+
+    (left, period)
+}
+
+/// Finds the first occurrence of the pattern using the "Two-Way" string matching algorithm.
+fn find<E, U>(input: &[E], pattern: &[U]) -> Option<usize>
+where
+    E: PartialEq<U>,
+    U: Ord,
+{
+    let length: usize = pattern.len();
+
+    if length == 0 {
+        return Some(0);
+    }
+
+    let (critical, period): (usize, usize) = {
+        let less: (usize, usize) = maximal_suffix(pattern, Ordering::Less);
+        let greater: (usize, usize) = maximal_suffix(pattern, Ordering::Greater);
+
+        if less.0 > greater.0 {
+            less
+        } else {
+            greater
+        }
+    };
+
+    // For periodic patterns, the matched prefix is remembered across shifts by a whole period.
+    let periodic: bool = pattern.get(..critical) == pattern.get(period..period + critical);
+
+    let period: usize = if periodic {
+        period
+    } else {
+        critical.max(length - critical) + 1
+    };
+
+    let mut position: usize = 0;
+    let mut memory: usize = 0;
+
+    'search: while let Some(window) = input.get(position..position + length) {
+        for index in critical.max(memory)..length {
+            if window[index] != pattern[index] {
+                position += index - critical + 1;
+                memory = 0;
+
+                continue 'search;
+            }
+        }
+
+        for index in (memory..critical).rev() {
+            if window[index] != pattern[index] {
+                position += period;
+                memory = if periodic { length - period } else { 0 };
+
+                continue 'search;
+            }
+        }
+
+        return Some(position);
+    }
+
+    None
+}
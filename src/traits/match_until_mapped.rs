@@ -0,0 +1,45 @@
+use crate::{
+    result::{MappedMatch, Match},
+    traits::MatchUntil,
+};
+
+/// Provides interface for matching everything up to a single "static" pattern.
+/// This is a counter part of [`MatchUntil`], mapping the "matched" part to the passed value.
+///
+/// [`MatchUntil`]: trait.MatchUntil.html
+pub trait MatchUntilMapped<E, T, R, M>: Sized {
+    /// Matches everything before the first occurrence of the pattern, leaving the pattern in the rest.
+    /// See [`MatchUntil::match_until`].
+    ///
+    /// [`MatchUntil::match_until`]: trait.MatchUntil.html#tymethod.match_until
+    fn match_until_mapped(self, pattern: T, value: M) -> R;
+
+    /// Matches everything up to and including the first occurrence of the pattern.
+    /// See [`MatchUntil::match_until_including`].
+    ///
+    /// [`MatchUntil::match_until_including`]: trait.MatchUntil.html#tymethod.match_until_including
+    fn match_until_including_mapped(self, pattern: T, value: M) -> R;
+
+    /// Matches everything before the first occurrence of the pattern, failing when it doesn't occur.
+    /// See [`MatchUntil::match_until_found`].
+    ///
+    /// [`MatchUntil::match_until_found`]: trait.MatchUntil.html#tymethod.match_until_found
+    fn match_until_found_mapped(self, pattern: T, value: M) -> R;
+}
+
+impl<E, T, M, R, Q, I> MatchUntilMapped<E, T, MappedMatch<M, R, Q>, Q> for I
+where
+    Self: MatchUntil<E, T, Match<M, R>>,
+{
+    fn match_until_mapped(self, pattern: T, value: Q) -> MappedMatch<M, R, Q> {
+        self.match_until(pattern).map(value)
+    }
+
+    fn match_until_including_mapped(self, pattern: T, value: Q) -> MappedMatch<M, R, Q> {
+        self.match_until_including(pattern).map(value)
+    }
+
+    fn match_until_found_mapped(self, pattern: T, value: Q) -> MappedMatch<M, R, Q> {
+        self.match_until_found(pattern).map(value)
+    }
+}
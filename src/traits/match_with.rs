@@ -1,4 +1,7 @@
-use crate::result::Match;
+use crate::{
+    predicate::{ByPredicate, Predicate},
+    result::Match,
+};
 
 /// Provides interface for matching single "dynamic" pattern.
 /// This is a counter part of [`MatchStatic`].
@@ -74,3 +77,21 @@ where
         Match::new(Some(self), &self[self.len()..])
     }
 }
+
+impl<E, P> MatchWith<P, Match<Self, Self>, ByPredicate<E>> for &[E]
+where
+    P: Predicate<E>,
+{
+    fn match_with(self, pattern: P) -> Match<Self, Self> {
+        self.match_with(|element: &E| pattern.test(element))
+    }
+}
+
+impl<P> MatchWith<P, Match<Self, Self>, ByPredicate<char>> for &str
+where
+    P: Predicate<char>,
+{
+    fn match_with(self, pattern: P) -> Match<Self, Self> {
+        self.match_with(|element: &char| pattern.test(element))
+    }
+}
@@ -1,26 +1,32 @@
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
 use crate::{
-    result::Match,
+    input::Fragment,
+    predicate::{ByPredicate, Predicate},
+    result::{Expected, Found, Match, MatchFailed},
     traits::{MatchFail, MatchWith},
 };
 
 mod private {
-    pub trait SafeAsUsize {
-        fn as_usize(self) -> usize;
+    use core::convert::TryFrom;
+
+    pub trait SaturatingAsUsize {
+        fn saturating_as_usize(self) -> usize;
     }
 
-    macro_rules! impl_safe_as_usize {
+    macro_rules! impl_saturating_as_usize {
         ($($t: ty),+ $(,)?) => {
             $(
-                impl SafeAsUsize for $t {
-                    fn as_usize(self) -> usize {
-                        self as usize
+                impl SaturatingAsUsize for $t {
+                    fn saturating_as_usize(self) -> usize {
+                        usize::try_from(self).unwrap_or(if self > 0 { usize::MAX } else { 0 })
                     }
                 }
             )+
         };
     }
 
-    impl_safe_as_usize! {
+    impl_saturating_as_usize! {
         isize,
         usize,
 
@@ -30,364 +36,393 @@ mod private {
         u16,
         i32,
         u32,
-    }
-
-    #[cfg(target_pointer_width = "64")]
-    impl_safe_as_usize! {
         i64,
         u64,
+        i128,
+        u128,
     }
 }
 
-use private::SafeAsUsize;
-
-/// Provides interface for matching single "dynamic" pattern.
-/// This is a counter part of [`MatchStatic`].
-/// ## Inplementation & usage
-/// The fifth parameters are helper parameter which defaults to `()`.
-/// They can be used to implement overloading by saving the function parameters, for example.
-/// When this trait is used as a super trait, it is **strongly recommented** to put a
-/// fully generic type (with no constrains) as the helper parameter.
+use private::SaturatingAsUsize;
+
+/// Describes the accepted amounts of repetitions of a "dynamic" pattern.
+/// ## Notes
+/// Implemented for integers, meaning an exact amount, and for all range types over integers, e.g. `2..`,
+/// `..=5` and `3..7`, as well as for `..` and `(Bound<usize>, Bound<usize>)`.
+/// Negative amounts are treated as zero, while amounts that don't fit in `usize` are treated as `usize::MAX`.
+/// ## Counting `&str`
+/// Repetitions of `&str` are counted in characters (Unicode scalar values) by default.
+/// Wrapping the amounts in [`Bytes`] opts into counting bytes instead, in which case a character is matched
+/// only if all of its bytes fit within the maximum amount.
 ///
-/// [`MatchStatic`]: trait.MatchStatic.html
-pub trait MatchWithInRange<N, F, R, H1, H2 = ()>
-where
-    Self: MatchWith<F, R, H1>,
-{
-    /// Matches a "dynamic" pattern by taking a function instead with taking into account a minimum amount.
-    fn match_min_with(self, minimum: N, pattern: F) -> R;
+/// [`Bytes`]: struct.Bytes.html
+pub trait Repetition {
+    /// Returns the start and end bounds of the accepted amounts.
+    fn bounds(self) -> (Bound<usize>, Bound<usize>);
+
+    /// Returns boolean indicating whether repetitions of `&str` are counted in bytes instead of characters.
+    fn counts_bytes(&self) -> bool {
+        false
+    }
+}
 
-    /// Matches a "dynamic" pattern by taking a function instead with taking into account a maximum amount.
-    fn match_max_with(self, maximum: N, pattern: F) -> R;
+/// Wraps amounts of repetitions of `&str` so they are counted in bytes instead of characters.
+/// Has no effect on other inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bytes<B>(pub B);
 
-    /// Matches a "dynamic" pattern by taking a function instead with taking into account a minimum and maximum amount.
-    fn match_min_max_with(self, minimum: N, maximum: N, pattern: F) -> R;
+impl<B> Repetition for Bytes<B>
+where
+    B: Repetition,
+{
+    fn bounds(self) -> (Bound<usize>, Bound<usize>) {
+        self.0.bounds()
+    }
 
-    /// Matches a "dynamic" pattern by taking a function instead with taking into account a exact amount.
-    fn match_exact_with(self, count: N, pattern: F) -> R;
+    fn counts_bytes(&self) -> bool {
+        true
+    }
 }
 
-impl<E, N, F, H> MatchWithInRange<N, F, Match<Self, Self>, H, E> for &[E]
+fn saturating_bound<N>(bound: Bound<N>) -> Bound<usize>
 where
-    Self: MatchWith<F, Match<Self, Self>, H>
-        + for<'r> MatchWith<&'r mut dyn FnMut(E) -> bool, Match<Self, Self>, H>,
-    N: SafeAsUsize,
-    F: FnMut(E) -> bool,
+    N: SaturatingAsUsize,
 {
-    fn match_min_with(self, minimum: N, mut pattern: F) -> Match<Self, Self> {
-        let minimum: usize = minimum.as_usize();
+    match bound {
+        Bound::Included(amount) => Bound::Included(amount.saturating_as_usize()),
+        Bound::Excluded(amount) => Bound::Excluded(amount.saturating_as_usize()),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
 
-        if self.len() < minimum {
-            return Match::failed();
-        }
+macro_rules! impl_repetition {
+    ($($t: ty),+ $(,)?) => {
+        $(
+            impl Repetition for $t {
+                fn bounds(self) -> (Bound<usize>, Bound<usize>) {
+                    let count: usize = self.saturating_as_usize();
 
-        if let Ok((Some(matched), rest)) =
-            <Self as MatchWith<&mut _, _, _>>::match_with(self, &mut pattern).take()
-        {
-            if minimum <= matched.len() {
-                Match::new(Some(matched), rest)
-            } else {
-                Match::failed()
+                    (Bound::Included(count), Bound::Included(count))
+                }
             }
-        } else {
-            Match::failed()
-        }
-    }
 
-    fn match_max_with(self, maximum: N, mut pattern: F) -> Match<Self, Self> {
-        let mut maximum: usize = maximum.as_usize();
-
-        if maximum <= self.len() {
-            <Self as MatchWith<&mut dyn FnMut(_) -> bool, _, _>>::match_with(
-                self,
-                &mut move |element: E| {
-                    if maximum == 0 {
-                        false
-                    } else {
-                        maximum -= 1;
-
-                        pattern(element)
-                    }
-                },
-            )
-        } else {
-            <Self as MatchWith<&mut _, _, _>>::match_with(self, &mut pattern)
-        }
-    }
+            impl Repetition for Range<$t> {
+                fn bounds(self) -> (Bound<usize>, Bound<usize>) {
+                    (
+                        saturating_bound(Bound::Included(self.start)),
+                        saturating_bound(Bound::Excluded(self.end)),
+                    )
+                }
+            }
 
-    fn match_min_max_with(self, minimum: N, maximum: N, pattern: F) -> Match<Self, Self> {
-        let (minimum, maximum): (usize, usize) = (minimum.as_usize(), maximum.as_usize());
+            impl Repetition for RangeInclusive<$t> {
+                fn bounds(self) -> (Bound<usize>, Bound<usize>) {
+                    let (start, end): ($t, $t) = self.into_inner();
 
-        if maximum < minimum {
-            return Match::failed();
-        }
+                    (
+                        saturating_bound(Bound::Included(start)),
+                        saturating_bound(Bound::Included(end)),
+                    )
+                }
+            }
 
-        if self.len() < minimum {
-            return Match::failed();
-        }
+            impl Repetition for RangeFrom<$t> {
+                fn bounds(self) -> (Bound<usize>, Bound<usize>) {
+                    (saturating_bound(Bound::Included(self.start)), Bound::Unbounded)
+                }
+            }
 
-        if self.len() <= maximum {
-            return self.match_min_with(minimum, pattern);
-        }
+            impl Repetition for RangeTo<$t> {
+                fn bounds(self) -> (Bound<usize>, Bound<usize>) {
+                    (Bound::Unbounded, saturating_bound(Bound::Excluded(self.end)))
+                }
+            }
 
-        if let Ok((Some(matched), rest)) = self.match_max_with(minimum, pattern).take() {
-            if minimum <= matched.len() {
-                Match::new(Some(matched), rest)
-            } else {
-                Match::failed()
+            impl Repetition for RangeToInclusive<$t> {
+                fn bounds(self) -> (Bound<usize>, Bound<usize>) {
+                    (Bound::Unbounded, saturating_bound(Bound::Included(self.end)))
+                }
             }
-        } else {
-            Match::failed()
-        }
-    }
+        )+
+    };
+}
 
-    fn match_exact_with(self, count: N, pattern: F) -> Match<Self, Self> {
-        let count: usize = count.as_usize();
+impl_repetition! {
+    isize,
+    usize,
+
+    i8,
+    u8,
+    i16,
+    u16,
+    i32,
+    u32,
+    i64,
+    u64,
+    i128,
+    u128,
+}
 
-        if self.len() < count {
-            return Match::failed();
-        }
+impl Repetition for RangeFull {
+    fn bounds(self) -> (Bound<usize>, Bound<usize>) {
+        (Bound::Unbounded, Bound::Unbounded)
+    }
+}
 
-        self.match_min_max_with(count, count, pattern)
+impl Repetition for (Bound<usize>, Bound<usize>) {
+    fn bounds(self) -> (Bound<usize>, Bound<usize>) {
+        self
     }
 }
 
-impl<E, N, F, H> MatchWithInRange<N, F, Match<Self, Self>, H, &E> for &[E]
+/// Returns the minimum and the inclusive maximum accepted amounts.
+/// Returns `None` when no amount is accepted, i.e. the range is empty or inverted.
+pub(crate) fn limits<B>(range: B) -> Option<(usize, Option<usize>)>
 where
-    Self: MatchWith<F, Match<Self, Self>, H>
-        + for<'r> MatchWith<&'r mut dyn FnMut(&E) -> bool, Match<Self, Self>, H>,
-    N: SafeAsUsize,
-    F: FnMut(&E) -> bool,
+    B: Repetition,
 {
-    fn match_min_with(self, minimum: N, mut pattern: F) -> Match<Self, Self> {
-        let minimum: usize = minimum.as_usize();
+    let (start, end): (Bound<usize>, Bound<usize>) = range.bounds();
+
+    let minimum: usize = match start {
+        Bound::Included(minimum) => minimum,
+        Bound::Excluded(minimum) => minimum.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+
+    let maximum: Option<usize> = match end {
+        Bound::Included(maximum) => Some(maximum),
+        Bound::Excluded(maximum) => Some(maximum.checked_sub(1)?),
+        Bound::Unbounded => None,
+    };
+
+    match maximum {
+        Some(maximum) if maximum < minimum => None,
+        _ => Some((minimum, maximum)),
+    }
+}
 
-        if self.len() < minimum {
-            return Match::failed();
-        }
+/// Consumes a repetition of the given size from the remaining amount, returning whether it fitted.
+fn within(maximum: &mut Option<usize>, size: usize) -> bool {
+    match maximum {
+        Some(maximum) if *maximum < size => false,
+        Some(maximum) => {
+            *maximum -= size;
 
-        if let Ok((Some(matched), rest)) = self.match_with(&mut pattern).take() {
-            if minimum <= matched.len() {
-                Match::new(Some(matched), rest)
-            } else {
-                Match::failed()
-            }
-        } else {
-            Match::failed()
+            true
         }
+        None => true,
+    }
+}
+
+/// Returns the size of a character's repetition, depending on whether bytes are counted.
+fn size_of(element: char, bytes: bool) -> usize {
+    if bytes {
+        element.len_utf8()
+    } else {
+        1
     }
+}
 
-    fn match_max_with(self, maximum: N, mut pattern: F) -> Match<Self, Self> {
-        let mut maximum: usize = maximum.as_usize();
+fn repeat<I, B, P>(input: I, range: B, match_limited: P) -> Match<I, I>
+where
+    I: Fragment,
+    B: Repetition,
+    P: FnOnce(I, Option<usize>, bool) -> Match<I, I>,
+{
+    let remaining: usize = input.length();
+
+    let bytes: bool = range.counts_bytes();
+
+    let (minimum, maximum): (usize, Option<usize>) = if let Some(limits) = limits(range) {
+        limits
+    } else {
+        return Match::failed_with(MatchFailed::new(
+            remaining,
+            Expected::Nothing,
+            Found::Unknown,
+        ));
+    };
+
+    let expected: Expected = match maximum {
+        None => Expected::AtLeast(minimum),
+        Some(maximum) if maximum == minimum => Expected::Exactly(minimum),
+        Some(maximum) => Expected::Between(minimum, maximum),
+    };
+
+    if remaining < minimum {
+        return Match::failed_with(MatchFailed::new(remaining, expected, Found::EndOfInput));
+    }
 
-        if maximum <= self.len() {
-            self.match_with(&mut move |element: &E| {
-                if maximum == 0 {
-                    false
-                } else {
-                    maximum -= 1;
+    if let Ok((Some(matched), rest)) = match_limited(input, maximum, bytes).take() {
+        let count: usize = matched.count(bytes);
 
-                    pattern(element)
-                }
-            })
+        if minimum <= count {
+            Match::new(Some(matched), rest)
+        } else if rest.length() == 0 {
+            Match::failed_with(MatchFailed::new(remaining, expected, Found::EndOfInput))
         } else {
-            self.match_with(&mut pattern)
+            Match::failed_with(MatchFailed::new(remaining, expected, Found::Count(count)))
         }
+    } else {
+        Match::failed()
     }
+}
 
-    fn match_min_max_with(self, minimum: N, maximum: N, pattern: F) -> Match<Self, Self> {
-        let (minimum, maximum): (usize, usize) = (minimum.as_usize(), maximum.as_usize());
-
-        if maximum < minimum {
-            return Match::failed();
-        }
+/// Provides interface for matching single "dynamic" pattern.
+/// This is a counter part of [`MatchStatic`].
+/// ## Inplementation & usage
+/// The third and forth parameters are helper parameters, the latter defaulting to `()`.
+/// They can be used to implement overloading by saving the function parameters, for example.
+/// When this trait is used as a super trait, it is **strongly recommented** to put a
+/// fully generic type (with no constrains) as the helper parameter.
+/// ## Amounts
+/// All methods delegate to [`match_range_with`], which accepts any [`Repetition`].
+/// A range that accepts no amount at all, e.g. `3..3` or `5..2`, never matches and fails with
+/// [`Expected::Nothing`].
+///
+/// [`MatchStatic`]: trait.MatchStatic.html
+/// [`match_range_with`]: #tymethod.match_range_with
+/// [`Repetition`]: trait.Repetition.html
+/// [`Expected::Nothing`]: ../result/enum.Expected.html#variant.Nothing
+pub trait MatchWithInRange<F, R, H1, H2 = ()>
+where
+    Self: MatchWith<F, R, H1>,
+{
+    /// Matches a "dynamic" pattern by taking a function instead with taking into account a range of amounts.
+    fn match_range_with<B>(self, range: B, pattern: F) -> R
+    where
+        B: Repetition;
 
-        if self.len() < minimum {
-            return Match::failed();
-        }
+    /// Matches a "dynamic" pattern by taking a function instead with taking into account a minimum amount.
+    fn match_min_with<N>(self, minimum: N, pattern: F) -> R
+    where
+        RangeFrom<N>: Repetition,
+    {
+        self.match_range_with(minimum.., pattern)
+    }
 
-        if self.len() <= maximum {
-            return self.match_min_with(minimum, pattern);
-        }
+    /// Matches a "dynamic" pattern by taking a function instead with taking into account a maximum amount.
+    fn match_max_with<N>(self, maximum: N, pattern: F) -> R
+    where
+        RangeToInclusive<N>: Repetition,
+    {
+        self.match_range_with(..=maximum, pattern)
+    }
 
-        if let Ok((Some(matched), rest)) = self.match_max_with(minimum, pattern).take() {
-            if minimum <= matched.len() {
-                Match::new(Some(matched), rest)
-            } else {
-                Match::failed()
-            }
-        } else {
-            Match::failed()
-        }
+    /// Matches a "dynamic" pattern by taking a function instead with taking into account a minimum and maximum amount.
+    fn match_min_max_with<N>(self, minimum: N, maximum: N, pattern: F) -> R
+    where
+        RangeInclusive<N>: Repetition,
+    {
+        self.match_range_with(minimum..=maximum, pattern)
     }
 
-    fn match_exact_with(self, count: N, pattern: F) -> Match<Self, Self> {
-        let count: usize = count.as_usize();
+    /// Matches a "dynamic" pattern by taking a function instead with taking into account a exact amount.
+    fn match_exact_with<N>(self, count: N, pattern: F) -> R
+    where
+        N: Repetition,
+    {
+        self.match_range_with(count, pattern)
+    }
+}
 
-        if self.len() < count {
-            return Match::failed();
-        }
+impl<E, F, H> MatchWithInRange<F, Match<Self, Self>, H, E> for &[E]
+where
+    Self: MatchWith<F, Match<Self, Self>, H>
+        + for<'r> MatchWith<&'r mut dyn FnMut(E) -> bool, Match<Self, Self>, H>,
+    F: FnMut(E) -> bool,
+{
+    fn match_range_with<B>(self, range: B, mut pattern: F) -> Match<Self, Self>
+    where
+        B: Repetition,
+    {
+        repeat(self, range, |input, mut maximum, _| {
+            <Self as MatchWith<&mut dyn FnMut(_) -> bool, _, _>>::match_with(
+                input,
+                &mut move |element: E| within(&mut maximum, 1) && pattern(element),
+            )
+        })
+    }
+}
 
-        self.match_min_max_with(count, count, pattern)
+impl<E, F, H> MatchWithInRange<F, Match<Self, Self>, H, &E> for &[E]
+where
+    Self: MatchWith<F, Match<Self, Self>, H>
+        + for<'r> MatchWith<&'r mut dyn FnMut(&E) -> bool, Match<Self, Self>, H>,
+    F: FnMut(&E) -> bool,
+{
+    fn match_range_with<B>(self, range: B, mut pattern: F) -> Match<Self, Self>
+    where
+        B: Repetition,
+    {
+        repeat(self, range, |input, mut maximum, _| {
+            input.match_with(&mut move |element: &E| within(&mut maximum, 1) && pattern(element))
+        })
     }
 }
 
-impl<N, F, H> MatchWithInRange<N, F, Match<Self, Self>, H, char> for &str
+impl<F, H> MatchWithInRange<F, Match<Self, Self>, H, char> for &str
 where
     Self: MatchWith<F, Match<Self, Self>, H>
         + for<'r> MatchWith<&'r mut dyn FnMut(char) -> bool, Match<Self, Self>, H>,
-    N: SafeAsUsize,
     F: FnMut(char) -> bool,
 {
-    fn match_min_with(self, minimum: N, mut pattern: F) -> Match<Self, Self> {
-        let minimum: usize = minimum.as_usize();
-
-        if self.len() < minimum {
-            return Match::failed();
-        }
-
-        if let Ok((Some(matched), rest)) = self.match_with(&mut pattern).take() {
-            if minimum <= matched.len() {
-                Match::new(Some(matched), rest)
-            } else {
-                Match::failed()
-            }
-        } else {
-            Match::failed()
-        }
-    }
-
-    fn match_max_with(self, maximum: N, mut pattern: F) -> Match<Self, Self> {
-        let mut maximum: usize = maximum.as_usize();
-
-        if maximum <= self.len() {
-            self.match_with(&mut move |element: char| {
-                if maximum == 0 {
-                    false
-                } else {
-                    maximum -= 1;
-
-                    pattern(element)
-                }
+    fn match_range_with<B>(self, range: B, mut pattern: F) -> Match<Self, Self>
+    where
+        B: Repetition,
+    {
+        repeat(self, range, |input, mut maximum, bytes| {
+            input.match_with(&mut move |element: char| {
+                within(&mut maximum, size_of(element, bytes)) && pattern(element)
             })
-        } else {
-            self.match_with(&mut pattern)
-        }
-    }
-
-    fn match_min_max_with(self, minimum: N, maximum: N, pattern: F) -> Match<Self, Self> {
-        let (minimum, maximum): (usize, usize) = (minimum.as_usize(), maximum.as_usize());
-
-        if maximum < minimum {
-            return Match::failed();
-        }
-
-        if self.len() < minimum {
-            return Match::failed();
-        }
-
-        if self.len() <= maximum {
-            return self.match_min_with(minimum, pattern);
-        }
-
-        if let Ok((Some(matched), rest)) = self.match_max_with(minimum, pattern).take() {
-            if minimum <= matched.len() {
-                Match::new(Some(matched), rest)
-            } else {
-                Match::failed()
-            }
-        } else {
-            Match::failed()
-        }
-    }
-
-    fn match_exact_with(self, count: N, pattern: F) -> Match<Self, Self> {
-        let count: usize = count.as_usize();
-
-        if self.len() < count {
-            return Match::failed();
-        }
-
-        self.match_min_max_with(count, count, pattern)
+        })
     }
 }
 
-impl<N, F, H> MatchWithInRange<N, F, Match<Self, Self>, H, &char> for &str
+impl<F, H> MatchWithInRange<F, Match<Self, Self>, H, &char> for &str
 where
     Self: MatchWith<F, Match<Self, Self>, H>
         + for<'r> MatchWith<&'r mut dyn FnMut(&char) -> bool, Match<Self, Self>, H>,
-    N: SafeAsUsize,
     F: FnMut(&char) -> bool,
 {
-    fn match_min_with(self, minimum: N, mut pattern: F) -> Match<Self, Self> {
-        let minimum: usize = minimum.as_usize();
-
-        if self.len() < minimum {
-            return Match::failed();
-        }
-
-        if let Ok((Some(matched), rest)) = self.match_with(&mut pattern).take() {
-            if minimum <= matched.len() {
-                Match::new(Some(matched), rest)
-            } else {
-                Match::failed()
-            }
-        } else {
-            Match::failed()
-        }
-    }
-
-    fn match_max_with(self, maximum: N, mut pattern: F) -> Match<Self, Self> {
-        let mut maximum: usize = maximum.as_usize();
-
-        if maximum <= self.len() {
-            self.match_with(&mut move |element: &char| {
-                if maximum == 0 {
-                    false
-                } else {
-                    maximum -= 1;
-
-                    pattern(element)
-                }
+    fn match_range_with<B>(self, range: B, mut pattern: F) -> Match<Self, Self>
+    where
+        B: Repetition,
+    {
+        repeat(self, range, |input, mut maximum, bytes| {
+            input.match_with(&mut move |element: &char| {
+                within(&mut maximum, size_of(*element, bytes)) && pattern(element)
             })
-        } else {
-            self.match_with(&mut pattern)
-        }
+        })
     }
+}
 
-    fn match_min_max_with(self, minimum: N, maximum: N, pattern: F) -> Match<Self, Self> {
-        let (minimum, maximum): (usize, usize) = (minimum.as_usize(), maximum.as_usize());
-
-        if maximum < minimum {
-            return Match::failed();
-        }
-
-        if self.len() < minimum {
-            return Match::failed();
-        }
-
-        if self.len() <= maximum {
-            return self.match_min_with(minimum, pattern);
-        }
-
-        if let Ok((Some(matched), rest)) = self.match_max_with(minimum, pattern).take() {
-            if minimum <= matched.len() {
-                Match::new(Some(matched), rest)
-            } else {
-                Match::failed()
-            }
-        } else {
-            Match::failed()
-        }
+impl<E, P> MatchWithInRange<P, Match<Self, Self>, ByPredicate<E>, ByPredicate<E>> for &[E]
+where
+    P: Predicate<E>,
+{
+    fn match_range_with<B>(self, range: B, pattern: P) -> Match<Self, Self>
+    where
+        B: Repetition,
+    {
+        <Self as MatchWithInRange<_, _, &E, &E>>::match_range_with(self, range, |element: &E| {
+            pattern.test(element)
+        })
     }
+}
 
-    fn match_exact_with(self, count: N, pattern: F) -> Match<Self, Self> {
-        let count: usize = count.as_usize();
-
-        if self.len() < count {
-            return Match::failed();
-        }
-
-        self.match_min_max_with(count, count, pattern)
+impl<P> MatchWithInRange<P, Match<Self, Self>, ByPredicate<char>, ByPredicate<char>> for &str
+where
+    P: Predicate<char>,
+{
+    fn match_range_with<B>(self, range: B, pattern: P) -> Match<Self, Self>
+    where
+        B: Repetition,
+    {
+        <Self as MatchWithInRange<_, _, &char, &char>>::match_range_with(
+            self,
+            range,
+            |element: &char| pattern.test(element),
+        )
     }
 }
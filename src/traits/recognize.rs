@@ -0,0 +1,48 @@
+use crate::result::{Match, TransformMatch};
+
+/// Provides interface for matching with the whole consumed part of the input as the "matched" part.
+/// ## Inplementation & usage
+/// The third parameter is a helper parameter which defaults to `()`.
+/// It can be used to implement overloading by saving the function parameters, for example.
+/// When this trait is used as a super trait, it is **strongly recommented** to put a
+/// fully generic type (with no constrains) as the helper parameter.
+pub trait Recognize<F, R, H = ()>: Sized {
+    /// Matches by using the passed function, replacing the "matched" part with the consumed part of the input.
+    fn recognize(self, f: F) -> R;
+}
+
+impl<E, F, R, T> Recognize<F, Match<Self, Self>, T> for &[E]
+where
+    F: FnOnce(Self) -> R,
+    R: Into<Match<T, Self>>,
+{
+    fn recognize(self, f: F) -> Match<Self, Self> {
+        f(self).into().transform(|_, rest| {
+            if let Some(consumed) = self.len().checked_sub(rest.len()) {
+                TransformMatch::Full(&self[..consumed], rest)
+            } else {
+                TransformMatch::Failed
+            }
+        })
+    }
+}
+
+impl<F, R, T> Recognize<F, Match<Self, Self>, T> for &str
+where
+    F: FnOnce(Self) -> R,
+    R: Into<Match<T, Self>>,
+{
+    fn recognize(self, f: F) -> Match<Self, Self> {
+        f(self).into().transform(|_, rest| {
+            if let Some(consumed) = self
+                .len()
+                .checked_sub(rest.len())
+                .and_then(|consumed| self.get(..consumed))
+            {
+                TransformMatch::Full(consumed, rest)
+            } else {
+                TransformMatch::Failed
+            }
+        })
+    }
+}
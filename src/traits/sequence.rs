@@ -0,0 +1,13 @@
+use crate::result::SequenceMatch;
+
+/// Provides interface for creating sequences that accumulate the "matched" parts.
+pub trait Sequence: Sized {
+    /// Creates a new sequence, starting with an empty tuple of "matched" parts.
+    fn sequence(self) -> SequenceMatch<(), Self> {
+        SequenceMatch::new(self)
+    }
+}
+
+impl<E> Sequence for &[E] {}
+
+impl Sequence for &str {}